@@ -19,6 +19,7 @@
 // SOFTWARE.
 
 use crate::Map;
+use core::mem;
 
 impl<K: Clone + PartialEq, V: Clone, const N: usize> Clone for Map<K, V, N> {
     fn clone(&self) -> Self {
@@ -28,6 +29,72 @@ impl<K: Clone + PartialEq, V: Clone, const N: usize> Clone for Map<K, V, N> {
         }
         m
     }
+
+    /// Clone the pairs of the source into the slots already occupied
+    /// in this map, instead of dropping and rebuilding all of them.
+    fn clone_from(&mut self, source: &Self) {
+        let common = self.next.min(source.next);
+        for i in 0..common {
+            let dst = unsafe { self.pairs[i].assume_init_mut() };
+            match (dst.as_mut(), source.item(i)) {
+                (Some((dk, dv)), Some((sk, sv))) => {
+                    dk.clone_from(sk);
+                    dv.clone_from(sv);
+                }
+                (_, src) => *dst = src.cloned(),
+            }
+        }
+        let old = mem::replace(&mut self.next, common);
+        for i in common..old {
+            unsafe { self.pairs[i].assume_init_drop() };
+        }
+        for i in common..source.next {
+            self.pairs[i].write(source.item(i).cloned());
+        }
+        self.next = source.next;
+        self.check_invariants();
+    }
+}
+
+impl<K: Clone + PartialEq, V: Clone, const N: usize> Map<K, V, N> {
+    /// Make a new map of a different capacity, with clones of all pairs.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are too many pairs for the new capacity,
+    /// exactly like [`Map::insert`] does.
+    #[inline]
+    #[must_use]
+    pub fn resize<const M: usize>(&self) -> Map<K, V, M> {
+        let mut m: Map<K, V, M> = Map::new();
+        for (k, v) in self {
+            Map::<K, V, M>::check_slot(m.next);
+            m.pairs[m.next].write(Some((k.clone(), v.clone())));
+            m.next += 1;
+        }
+        m
+    }
+
+    /// Make a new map of a different capacity, with clones of the pairs
+    /// matching the predicate.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are too many matching pairs for the new
+    /// capacity, exactly like [`Map::insert`] does.
+    #[inline]
+    #[must_use]
+    pub fn filter<const M: usize, F: FnMut(&K, &V) -> bool>(&self, mut pred: F) -> Map<K, V, M> {
+        let mut m: Map<K, V, M> = Map::new();
+        for (k, v) in self {
+            if pred(k, v) {
+                Map::<K, V, M>::check_slot(m.next);
+                m.pairs[m.next].write(Some((k.clone(), v.clone())));
+                m.next += 1;
+            }
+        }
+        m
+    }
 }
 
 #[cfg(test)]
@@ -43,8 +110,96 @@ mod test {
     }
 
     #[test]
+    fn grows_into_larger_map() {
+        let mut m: Map<u8, u8, 4> = Map::new();
+        for i in 0..4 {
+            m.insert(i, i * 2);
+        }
+        let mut g: Map<u8, u8, 8> = m.resize();
+        assert_eq!(4, g.len());
+        g.insert(4, 8);
+        assert_eq!(8, g[&4]);
+        assert_eq!(6, g[&3]);
+    }
+
+    #[test]
+    fn shrinks_into_smaller_map() {
+        let mut m: Map<u8, u8, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, i);
+        }
+        m.remove(&1);
+        m.remove(&4);
+        let s: Map<u8, u8, 4> = m.resize();
+        assert_eq!(s.keys().collect::<Vec<_>>(), [&0, &2, &3, &5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "No more keys available in the map")]
+    #[cfg(all(debug_assertions, feature = "std"))]
+    fn shrinks_too_much() {
+        let m: Map<usize, u8, 8> = Map::from_fn(|i| (i, 0));
+        let _s: Map<usize, u8, 4> = m.resize();
+    }
+
+    #[test]
+    #[allow(clippy::redundant_clone)]
     fn empty_map_can_be_cloned() {
         let m: Map<u8, u8, 0> = Map::new();
         assert!(m.clone().is_empty());
     }
+
+    #[test]
+    fn filters_into_smaller_map() {
+        let mut m: Map<u8, u8, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, i * 3);
+        }
+        let f: Map<u8, u8, 3> = m.filter(|_, v| v % 2 == 0);
+        assert_eq!(3, f.len());
+        assert_eq!(f.keys().collect::<Vec<_>>(), [&0, &2, &4]);
+        assert_eq!(6, m.len());
+    }
+
+    #[test]
+    fn clones_from_shorter_and_longer_maps() {
+        let mut src: Map<u8, String, 8> = Map::new();
+        for i in 0..3 {
+            src.insert(i, i.to_string());
+        }
+        let mut dst: Map<u8, String, 8> = Map::new();
+        for i in 0..6 {
+            dst.insert(i, String::with_capacity(64));
+        }
+        let before = dst[&0].as_ptr();
+        dst.clone_from(&src);
+        assert!(dst == src);
+        assert_eq!(src.next, dst.next);
+        assert_eq!(before, dst[&0].as_ptr());
+        for i in 3..7 {
+            src.insert(i, i.to_string());
+        }
+        dst.clone_from(&src);
+        assert!(dst == src);
+        assert_eq!(7, dst.next);
+    }
+
+    #[test]
+    fn clones_from_into_map_with_holes() {
+        let mut src: Map<u8, u8, 8> = Map::new();
+        for i in 0..4 {
+            src.insert(i, i);
+        }
+        let mut dst: Map<u8, u8, 8> = Map::new();
+        for i in 10..15 {
+            dst.insert(i, i);
+        }
+        dst.remove(&11);
+        dst.remove(&13);
+        dst.clone_from(&src);
+        assert!(dst == src);
+        assert_eq!(4, dst.len());
+        assert_eq!(src.next, dst.next);
+        assert_eq!(dst.keys().collect::<Vec<_>>(), [&0, &1, &2, &3]);
+    }
 }
@@ -0,0 +1,64 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Move all pairs of this map and of the other one into a new map,
+    /// which is large enough to hold them all.
+    ///
+    /// If a key is present in both maps, the value from the other one wins.
+    ///
+    /// This requires nightly Rust and is only available when compiled
+    /// with `RUSTFLAGS="--cfg unstable_const"`.
+    #[inline]
+    #[must_use]
+    pub fn concat<const B: usize>(self, other: Map<K, V, B>) -> Map<K, V, { N + B }>
+    where
+        [(); N + B]:,
+    {
+        let mut m = Map::new();
+        for (k, v) in self {
+            m.insert(k, v);
+        }
+        for (k, v) in other {
+            m.insert(k, v);
+        }
+        m
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn concatenates_two_maps() {
+        let a: Map<i32, &str, 2> = Map::from([(1, "one"), (2, "two")]);
+        let b: Map<i32, &str, 3> = Map::from([(2, "deux"), (3, "trois"), (4, "quatre")]);
+        let m = a.concat(b);
+        assert_eq!(5, m.capacity());
+        assert_eq!(4, m.len());
+        assert_eq!("one", m[&1]);
+        assert_eq!("deux", m[&2]);
+        assert_eq!("quatre", m[&4]);
+    }
+}
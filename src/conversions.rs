@@ -0,0 +1,117 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{CapacityError, Map};
+use std::collections::{BTreeMap, HashMap};
+
+impl<K: PartialEq, V, const N: usize> TryFrom<BTreeMap<K, V>> for Map<K, V, N> {
+    type Error = CapacityError<BTreeMap<K, V>>;
+
+    /// Move all pairs of the [`BTreeMap`] into a new [`Map`].
+    ///
+    /// If there are more pairs than the capacity of the [`Map`], the
+    /// [`BTreeMap`] is returned back in the error, untouched.
+    #[inline]
+    fn try_from(b: BTreeMap<K, V>) -> Result<Self, Self::Error> {
+        if b.len() > N {
+            return Err(CapacityError { value: b });
+        }
+        Ok(Self::from_unique(b))
+    }
+}
+
+impl<K: PartialEq, V, S, const N: usize> TryFrom<HashMap<K, V, S>> for Map<K, V, N> {
+    type Error = CapacityError<HashMap<K, V, S>>;
+
+    /// Move all pairs of the [`HashMap`] into a new [`Map`].
+    ///
+    /// If there are more pairs than the capacity of the [`Map`], the
+    /// [`HashMap`] is returned back in the error, untouched.
+    #[inline]
+    fn try_from(h: HashMap<K, V, S>) -> Result<Self, Self::Error> {
+        if h.len() > N {
+            return Err(CapacityError { value: h });
+        }
+        Ok(Self::from_unique(h))
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Internal function to make a map from the pairs with unique keys,
+    /// which surely fit into it.
+    #[inline]
+    fn from_unique<I: IntoIterator<Item = (K, V)>>(pairs: I) -> Self {
+        let mut m = Self::new();
+        for p in pairs {
+            m.pairs[m.next].write(Some(p));
+            m.next += 1;
+        }
+        m
+    }
+}
+
+impl<K: PartialEq + Ord, V, const N: usize> From<Map<K, V, N>> for BTreeMap<K, V> {
+    /// Move all pairs of the [`Map`] into a new [`BTreeMap`], which sorts them by keys.
+    #[inline]
+    fn from(m: Map<K, V, N>) -> Self {
+        m.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn converts_back_and_forth() {
+        let mut m: Map<i32, &str, 4> = Map::new();
+        m.insert(3, "three");
+        m.insert(1, "one");
+        m.insert(2, "two");
+        let b = BTreeMap::from(m);
+        assert_eq!(b.keys().collect::<Vec<_>>(), [&1, &2, &3]);
+        let m: Map<i32, &str, 4> = Map::try_from(b).unwrap();
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&1, &2, &3]);
+        assert_eq!("two", m[&2]);
+    }
+
+    #[test]
+    fn refuses_too_large_btree_map() {
+        let b: BTreeMap<i32, i32> = (0..5).map(|i| (i, i)).collect();
+        let r: Result<Map<i32, i32, 4>, _> = Map::try_from(b);
+        assert_eq!(5, r.err().unwrap().value.len());
+    }
+
+    #[test]
+    fn converts_from_hash_map() {
+        let h: HashMap<i32, i32> = (0..4).map(|i| (i, i * 2)).collect();
+        let m: Map<i32, i32, 4> = Map::try_from(h).unwrap();
+        assert_eq!(4, m.len());
+        assert_eq!(6, m[&3]);
+    }
+
+    #[test]
+    fn refuses_too_large_hash_map() {
+        let h: HashMap<i32, i32> = (0..5).map(|i| (i, i)).collect();
+        let r: Result<Map<i32, i32, 4>, CapacityError<HashMap<i32, i32>>> = Map::try_from(h);
+        assert_eq!(5, r.err().unwrap().value.len());
+    }
+}
@@ -24,13 +24,12 @@ use core::mem::MaybeUninit;
 impl<K: PartialEq, V, const N: usize> Default for Map<K, V, N> {
     /// Make a default empty [`Map`].
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+impl<K, V, const N: usize> Map<K, V, N> {
     /// Make it.
     ///
     /// The size of the map is defined by the generic argument. For example,
@@ -46,9 +45,57 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
             }
         }
     }
+
+    /// Make it, exactly like [`Map::new`] does.
+    ///
+    /// The capacity is not an argument, but the generic parameter `N`,
+    /// which makes the intent explicit at the call site:
+    ///
+    /// ```
+    /// let m = micromap::Map::<u8, u8, 16>::with_capacity();
+    /// assert_eq!(16, m.capacity());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_capacity() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Make it with a single pair inside.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the capacity of the map is zero, exactly like
+    /// [`Map::insert`] does.
+    #[inline]
+    #[must_use]
+    pub const fn singleton(k: K, v: V) -> Self {
+        Self::check_slot(0);
+        let mut m = Self::new();
+        m.pairs[0].write(Some((k, v)));
+        m.next = 1;
+        m
+    }
+
+    /// Make it by calling the function for every index in `0..N`.
+    ///
+    /// Every pair returned by the function is inserted, so duplicate keys
+    /// overwrite each other, just like they do in [`Map::insert`].
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F: FnMut(usize) -> (K, V)>(mut f: F) -> Self {
+        let mut m = Self::new();
+        for i in 0..N {
+            let (k, v) = f(i);
+            m.insert(k, v);
+        }
+        m
+    }
 }
 
-impl<K: PartialEq, V, const N: usize> Drop for Map<K, V, N> {
+impl<K, V, const N: usize> Drop for Map<K, V, N> {
     fn drop(&mut self) {
         for i in 0..self.next {
             unsafe {
@@ -75,6 +122,28 @@ mod test {
         assert_eq!(0, m.len());
     }
 
+    #[test]
+    fn makes_singleton_map() {
+        let m = Map::<_, _, 4>::singleton("x", 1);
+        assert_eq!(1, m.len());
+        assert_eq!(1, m["x"]);
+    }
+
+    #[test]
+    fn makes_map_from_fn() {
+        let m: Map<usize, usize, 4> = Map::from_fn(|i| (i, i * i));
+        assert_eq!(4, m.len());
+        assert_eq!(4, m[&2]);
+        assert_eq!(9, m[&3]);
+    }
+
+    #[test]
+    fn makes_map_from_fn_with_duplicates() {
+        let m: Map<usize, usize, 4> = Map::from_fn(|i| (i % 2, i));
+        assert_eq!(2, m.len());
+        assert_eq!(3, m[&1]);
+    }
+
     #[test]
     fn drops_correctly() {
         let _m: Map<Vec<u8>, u8, 8> = Map::new();
@@ -90,6 +159,18 @@ mod test {
         assert_eq!(Rc::strong_count(&k), 1);
     }
 
+    #[test]
+    fn drops_values_on_scope_exit() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        {
+            let mut m: Map<(), Rc<()>, 4> = Map::new();
+            m.insert((), Rc::clone(&v));
+            assert_eq!(2, Rc::strong_count(&v));
+        }
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
     #[test]
     fn drops_values() {
         use std::rc::Rc;
@@ -99,4 +180,18 @@ mod test {
         drop(m);
         assert_eq!(Rc::strong_count(&v), 1);
     }
+
+    #[test]
+    fn makes_map_of_keys_without_eq() {
+        struct NoEq;
+        let m: Map<NoEq, u8, 4> = Map::new();
+        assert_eq!(4, m.capacity());
+    }
+
+    #[test]
+    fn makes_with_capacity() {
+        let m = Map::<u8, u8, 16>::with_capacity();
+        assert!(m.is_empty());
+        assert_eq!(16, m.capacity());
+    }
 }
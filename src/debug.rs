@@ -22,19 +22,20 @@ use crate::Map;
 use core::fmt;
 use core::fmt::{Debug, Display, Formatter};
 
-impl<K: PartialEq + Display, V: Display, const N: usize> Display for Map<K, V, N> {
+impl<K: PartialEq + Display, V: Display, const N: usize> Debug for Map<K, V, N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        <&Self as Debug>::fmt(&self, f)
+        f.debug_map()
+            .entries(self.iter().map(|(k, v)| (Displayed(k), Displayed(v))))
+            .finish()
     }
 }
 
-impl<K: PartialEq + Display, V: Display, const N: usize> Debug for Map<K, V, N> {
+/// Internal wrapper to debug keys and values as they are displayed.
+struct Displayed<'a, T>(&'a T);
+
+impl<T: Display> Debug for Displayed<'_, T> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let mut parts = vec![];
-        for (k, v) in self.iter() {
-            parts.push(std::format!("{k}: {v}"));
-        }
-        f.write_str(std::format!("{{{}}}", parts.join(", ").as_str()).as_str())
+        Display::fmt(self.0, f)
     }
 }
 
@@ -48,14 +49,21 @@ mod test {
         let mut m: Map<String, i32, 10> = Map::new();
         m.insert("one".to_string(), 42);
         m.insert("two".to_string(), 16);
-        assert_eq!("{one: 42, two: 16}", format!("{:?}", m));
+        assert_eq!("{one: 42, two: 16}", format!("{m:?}"));
     }
 
     #[test]
-    fn displays_map() {
+    fn debugs_map_pretty() {
         let mut m: Map<String, i32, 10> = Map::new();
         m.insert("one".to_string(), 42);
         m.insert("two".to_string(), 16);
-        assert_eq!("{one: 42, two: 16}", format!("{}", m));
+        assert_eq!("{one: 42, two: 16}", format!("{m:?}"));
+        assert_eq!("{\n    one: 42,\n    two: 16,\n}", format!("{m:#?}"));
+    }
+
+    #[test]
+    fn debugs_empty_map() {
+        let m: Map<String, i32, 10> = Map::new();
+        assert_eq!("{}", format!("{m:?}"));
     }
 }
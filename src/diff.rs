@@ -0,0 +1,76 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Change, Map};
+
+impl<K: PartialEq, V: PartialEq, const N: usize> Map<K, V, N> {
+    /// Make an iterator over the changes, which turn this map into the other one.
+    ///
+    /// First, removed and modified pairs are visited, in the order of this map,
+    /// and then added pairs, in the order of the other map.
+    #[inline]
+    pub fn diff<'a, const M: usize>(
+        &'a self,
+        other: &'a Map<K, V, M>,
+    ) -> impl Iterator<Item = Change<'a, K, V>> {
+        let changed = self.iter().filter_map(|(k, v)| match other.get(k) {
+            None => Some(Change::Removed(k, v)),
+            Some(o) if o != v => Some(Change::Modified(k, v, o)),
+            Some(_) => None,
+        });
+        let added = other
+            .iter()
+            .filter(|(k, _)| !self.contains_key(*k))
+            .map(|(k, v)| Change::Added(k, v));
+        changed.chain(added)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn diffs_two_maps() {
+        let mut a: Map<&str, i32, 4> = Map::new();
+        a.insert("same", 1);
+        a.insert("gone", 2);
+        a.insert("changed", 3);
+        let mut b: Map<&str, i32, 8> = Map::new();
+        b.insert("new", 4);
+        b.insert("changed", 33);
+        b.insert("same", 1);
+        assert_eq!(
+            a.diff(&b).collect::<Vec<_>>(),
+            [
+                Change::Removed(&"gone", &2),
+                Change::Modified(&"changed", &3, &33),
+                Change::Added(&"new", &4),
+            ]
+        );
+    }
+
+    #[test]
+    fn diffs_equal_maps() {
+        let a: Map<usize, i32, 4> = Map::from_fn(|i| (i, 0));
+        assert_eq!(0, a.diff(&a).count());
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DrainFilter, Map};
+use core::iter::FusedIterator;
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Make an iterator, which removes and returns the pairs matching
+    /// the predicate, leaving all other pairs in the map.
+    #[inline]
+    pub const fn drain_filter<F: FnMut(&K, &mut V) -> bool>(
+        &mut self,
+        pred: F,
+    ) -> DrainFilter<'_, K, V, N, F> {
+        DrainFilter {
+            pos: 0,
+            map: self,
+            pred,
+        }
+    }
+}
+
+impl<K: PartialEq, V, const N: usize, F: FnMut(&K, &mut V) -> bool> Iterator
+    for DrainFilter<'_, K, V, N, F>
+{
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pos < self.map.next {
+            let i = self.pos;
+            self.pos += 1;
+            if let Some((k, v)) = unsafe { self.map.pairs[i].assume_init_mut() } {
+                if (self.pred)(k, v) {
+                    let p = unsafe { self.map.pairs[i].assume_init_mut() }.take();
+                    self.map.check_invariants();
+                    return p;
+                }
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.map.next - self.pos))
+    }
+}
+
+impl<K: PartialEq, V, const N: usize, F: FnMut(&K, &mut V) -> bool> FusedIterator
+    for DrainFilter<'_, K, V, N, F>
+{
+}
+
+impl<K: PartialEq, V, const N: usize, F: FnMut(&K, &mut V) -> bool> Drop
+    for DrainFilter<'_, K, V, N, F>
+{
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn drains_matching_pairs() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, i);
+        }
+        let odd: Vec<_> = m.drain_filter(|k, _| k % 2 == 1).collect();
+        assert_eq!(vec![(1, 1), (3, 3), (5, 5)], odd);
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&0, &2, &4]);
+    }
+
+    #[test]
+    fn drains_the_rest_when_dropped() {
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, Rc::clone(&v));
+        }
+        let mut d = m.drain_filter(|k, _| *k >= 2);
+        assert_eq!(Some(2), d.next().map(|p| p.0));
+        drop(d);
+        assert_eq!(2, m.len());
+        assert_eq!(3, Rc::strong_count(&v));
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&0, &1]);
+    }
+}
@@ -0,0 +1,425 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Entry, Map, OccupiedEntry, VacantEntry};
+use core::borrow::Borrow;
+use core::mem;
+use core::mem::MaybeUninit;
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Get the given key's corresponding entry in the map for in-place manipulation.
+    #[inline]
+    pub fn entry(&mut self, k: K) -> Entry<'_, K, V, N> {
+        match self.slot_of(&k) {
+            Ok(index) => Entry::Occupied(OccupiedEntry { index, map: self }),
+            Err(index) => Entry::Vacant(VacantEntry {
+                key: k,
+                index,
+                map: self,
+            }),
+        }
+    }
+
+    /// Get a mutable reference to the value of the key, inserting the given
+    /// value first, if the key is absent.
+    ///
+    /// If the key is present, the given value is dropped.
+    #[inline]
+    pub fn get_or_insert(&mut self, k: K, v: V) -> &mut V {
+        self.entry(k).or_insert(v)
+    }
+
+    /// Get a mutable reference to the value of the key, inserting the result
+    /// of the default function first, if the key is absent.
+    ///
+    /// The function gets the key and is not called if the key is present.
+    #[inline]
+    pub fn get_or_insert_with_key<F: FnOnce(&K) -> V>(&mut self, k: K, default: F) -> &mut V {
+        self.entry(k).or_insert_with_key(default)
+    }
+
+    /// Insert the default value, if the key is absent, or update
+    /// the existing value with the function otherwise.
+    ///
+    /// If the key is present, the default value is dropped.
+    #[inline]
+    pub fn insert_or_update<F: FnOnce(&mut V)>(&mut self, k: K, default: V, update: F) {
+        match self.entry(k) {
+            Entry::Occupied(mut entry) => update(entry.get_mut()),
+            Entry::Vacant(entry) => {
+                entry.insert(default);
+            }
+        }
+    }
+
+    /// Internal function to find the slot of the key, or the first free
+    /// slot, where the key may be inserted, if it is absent.
+    #[inline]
+    pub(crate) fn slot_of<Q: PartialEq + ?Sized>(&self, k: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+    {
+        let mut free = self.next;
+        for i in 0..self.next {
+            match self.item(i) {
+                Some(p) => {
+                    if p.0.borrow() == k {
+                        return Ok(i);
+                    }
+                }
+                None => {
+                    if free == self.next {
+                        free = i;
+                    }
+                }
+            }
+        }
+        Err(free)
+    }
+
+    /// Internal function to put the pair into the free slot.
+    #[inline]
+    pub(crate) const fn write_slot(&mut self, i: usize, k: K, v: V) -> &mut V {
+        Self::check_slot(i);
+        self.pairs[i].write(Some((k, v)));
+        if i == self.next {
+            self.next += 1;
+        }
+        self.check_invariants();
+        &mut unsafe { self.pairs[i].assume_init_mut().as_mut().unwrap_unchecked() }.1
+    }
+
+    /// Get the entry of the first pair in the array, if there is one.
+    #[inline]
+    pub fn first_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, N>> {
+        let index = (0..self.next).find(|&i| self.item(i).is_some())?;
+        Some(OccupiedEntry { index, map: self })
+    }
+
+    /// Get the entry of the last pair in the array, if there is one.
+    #[inline]
+    pub fn last_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, N>> {
+        let index = (0..self.next).rev().find(|&i| self.item(i).is_some())?;
+        Some(OccupiedEntry { index, map: self })
+    }
+}
+
+impl<'a, K: PartialEq, V, const N: usize> Entry<'a, K, V, N> {
+    /// Get the key of the entry, either occupied or vacant.
+    #[inline]
+    #[must_use]
+    pub const fn key(&self) -> &K {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Ensure a value is in the entry by inserting the default if empty,
+    /// and return a mutable reference to the value in the entry.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensure a value is in the entry by inserting the result of the default
+    /// function if empty, and return a mutable reference to the value in the entry.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensure a value is in the entry by inserting the result of the default
+    /// function, which gets the key, if empty, and return a mutable reference
+    /// to the value in the entry.
+    #[inline]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => {
+                let v = default(entry.key());
+                entry.insert(v)
+            }
+        }
+    }
+
+    /// Modify the value of the entry, if it's occupied, and return
+    /// the entry back for further chaining.
+    #[inline]
+    #[must_use]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K: PartialEq, V: Default, const N: usize> Entry<'a, K, V, N> {
+    /// Ensure a value is in the entry by inserting the default value if empty,
+    /// and return a mutable reference to the value in the entry.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K: PartialEq, V, const N: usize> OccupiedEntry<'a, K, V, N> {
+    /// Get the key of the entry.
+    #[inline]
+    #[must_use]
+    pub const fn key(&self) -> &K {
+        &self.pair().0
+    }
+
+    /// Convert the entry into a mutable reference to its value.
+    #[inline]
+    #[must_use]
+    pub const fn into_mut(self) -> &'a mut V {
+        let p = unsafe { self.map.pairs[self.index].assume_init_mut() };
+        &mut unsafe { p.as_mut().unwrap_unchecked() }.1
+    }
+
+    /// Get a reference to the value of the entry.
+    #[inline]
+    #[must_use]
+    pub const fn get(&self) -> &V {
+        &self.pair().1
+    }
+
+    /// Get a mutable reference to the value of the entry.
+    #[inline]
+    pub const fn get_mut(&mut self) -> &mut V {
+        let p = unsafe { self.map.pairs[self.index].assume_init_mut() };
+        &mut unsafe { p.as_mut().unwrap_unchecked() }.1
+    }
+
+    /// Replace the value of the entry, returning the old one.
+    #[inline]
+    pub const fn insert(&mut self, v: V) -> V {
+        mem::replace(self.get_mut(), v)
+    }
+
+    /// Take the value out of the entry, and free its slot.
+    #[inline]
+    pub fn remove(self) -> V {
+        let p = mem::replace(&mut self.map.pairs[self.index], MaybeUninit::new(None));
+        self.map.check_invariants();
+        unsafe { p.assume_init().unwrap_unchecked() }.1
+    }
+
+    /// Internal function to get the pair of the entry, which is surely there.
+    #[inline]
+    const fn pair(&self) -> &(K, V) {
+        unsafe {
+            self.map.pairs[self.index]
+                .assume_init_ref()
+                .as_ref()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<'a, K: PartialEq, V, const N: usize> VacantEntry<'a, K, V, N> {
+    /// Get the key, which will be inserted together with the value.
+    #[inline]
+    #[must_use]
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Put the value into the free slot of the entry, together with
+    /// the key, and return a mutable reference to the value.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are too many pairs in the map already, exactly
+    /// like [`Map::insert`] does.
+    #[inline]
+    pub fn insert(self, v: V) -> &'a mut V {
+        self.map.write_slot(self.index, self.key, v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn counts_with_or_default() {
+        let mut m: Map<char, usize, 10> = Map::new();
+        for c in ['a', 'b', 'a', 'c', 'a', 'b'] {
+            *m.entry(c).or_default() += 1;
+        }
+        assert_eq!(3, m.len());
+        assert_eq!(3, m[&'a']);
+        assert_eq!(2, m[&'b']);
+        assert_eq!(1, m[&'c']);
+    }
+
+    #[test]
+    fn or_insert_keeps_existing_value() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("one", 1);
+        assert_eq!(1, *m.entry("one").or_insert(42));
+        assert_eq!(42, *m.entry("two").or_insert_with(|| 42));
+        assert_eq!(2, m.len());
+    }
+
+    #[test]
+    fn removes_first_and_last_entries() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i * 10);
+        }
+        m.remove(&0);
+        assert_eq!(10, m.first_entry().unwrap().remove());
+        assert_eq!(40, m.last_entry().unwrap().remove());
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&2, &3]);
+        *m.first_entry().unwrap().into_mut() += 1;
+        assert_eq!(21, m[&2]);
+    }
+
+    #[test]
+    fn no_entries_in_empty_map() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        assert!(m.first_entry().is_none());
+        m.insert(1, 1);
+        m.remove(&1);
+        assert!(m.last_entry().is_none());
+    }
+
+    #[test]
+    fn reuses_free_slot() {
+        let mut m: Map<i32, i32, 2> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.remove(&1);
+        *m.entry(3).or_default() = 3;
+        assert_eq!(2, m.len());
+        assert_eq!(3, m[&3]);
+    }
+
+    #[test]
+    fn gets_or_inserts_value() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 4> = Map::new();
+        m.get_or_insert(1, Rc::clone(&v));
+        assert_eq!(2, Rc::strong_count(&v));
+        let r = m.get_or_insert(1, Rc::clone(&v));
+        assert!(Rc::ptr_eq(&v, r));
+        assert_eq!(2, Rc::strong_count(&v));
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn reads_key_of_entry() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("one", 1);
+        assert_eq!(&"one", m.entry("one").key());
+        assert_eq!(&"two", m.entry("two").key());
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn modifies_through_occupied_entry() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("one", 1);
+        m.insert("two", 2);
+        if let Entry::Occupied(mut e) = m.entry("one") {
+            *e.get_mut() += 10;
+            assert_eq!(11, e.insert(100));
+            assert_eq!(100, *e.get());
+        }
+        assert_eq!(100, m["one"]);
+        if let Entry::Occupied(e) = m.entry("two") {
+            assert_eq!(2, e.remove());
+        }
+        assert_eq!(1, m.len());
+        assert!(!m.contains_key("two"));
+    }
+
+    #[test]
+    fn inserts_through_vacant_entry() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        if let Entry::Vacant(e) = m.entry("one") {
+            let v = e.insert(1);
+            *v += 41;
+        }
+        assert_eq!(42, m["one"]);
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn gets_or_inserts_with_key() {
+        let mut m: Map<&str, usize, 4> = Map::new();
+        assert_eq!(5, *m.get_or_insert_with_key("hello", |k| k.len()));
+        *m.get_or_insert_with_key("hello", |_| unreachable!()) += 1;
+        assert_eq!(6, m["hello"]);
+    }
+
+    #[test]
+    fn counts_with_and_modify() {
+        let mut m: Map<char, usize, 10> = Map::new();
+        for c in ['a', 'b', 'a', 'c', 'a', 'b'] {
+            m.entry(c).and_modify(|v| *v += 1).or_insert(1);
+        }
+        assert_eq!(3, m.len());
+        assert_eq!(3, m[&'a']);
+        assert_eq!(2, m[&'b']);
+        assert_eq!(1, m[&'c']);
+    }
+
+    #[test]
+    fn inserts_with_key() {
+        let mut m: Map<&str, usize, 4> = Map::new();
+        assert_eq!(3, *m.entry("one").or_insert_with_key(|k| k.len()));
+        assert_eq!(3, *m.entry("one").or_insert_with_key(|_| 42));
+    }
+
+    #[test]
+    fn builds_histogram_with_insert_or_update() {
+        let mut m: Map<char, usize, 4> = Map::new();
+        for c in ['a', 'b', 'a', 'a'] {
+            m.insert_or_update(c, 1, |v| *v += 1);
+        }
+        assert_eq!(3, m[&'a']);
+        assert_eq!(1, m[&'b']);
+        assert_eq!(2, m.len());
+    }
+
+    #[test]
+    fn drops_safely_after_entry_overflow() {
+        let r = std::panic::catch_unwind(|| {
+            let mut m: Map<String, usize, 1> = Map::new();
+            m.insert("one".to_string(), 1);
+            m.entry("two".to_string()).or_insert(2);
+        });
+        assert!(r.is_err());
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{EntryRef, Map, OccupiedEntry, VacantEntryRef};
+use alloc::borrow::ToOwned;
+use core::borrow::Borrow;
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Get the given key's corresponding entry in the map for in-place manipulation,
+    /// without taking the ownership of the key.
+    ///
+    /// The key is turned into an owned one only when a new pair is inserted.
+    #[inline]
+    pub fn entry_ref<'b, Q: PartialEq + ToOwned<Owned = K> + ?Sized>(
+        &mut self,
+        k: &'b Q,
+    ) -> EntryRef<'_, 'b, K, Q, V, N>
+    where
+        K: Borrow<Q>,
+    {
+        match self.slot_of(k) {
+            Ok(index) => EntryRef::Occupied(OccupiedEntry { index, map: self }),
+            Err(index) => EntryRef::Vacant(VacantEntryRef {
+                key: k,
+                index,
+                map: self,
+            }),
+        }
+    }
+}
+
+impl<'a, K: PartialEq, Q: ToOwned<Owned = K> + ?Sized, V, const N: usize>
+    EntryRef<'a, '_, K, Q, V, N>
+{
+    /// Ensure a value is in the entry by inserting the default if empty,
+    /// and return a mutable reference to the value in the entry.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert_value(default),
+        }
+    }
+
+    /// Ensure a value is in the entry by inserting the result of the default
+    /// function if empty, and return a mutable reference to the value in the entry.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert_value(default()),
+        }
+    }
+}
+
+impl<'a, K: PartialEq, Q: ToOwned<Owned = K> + ?Sized, V: Default, const N: usize>
+    EntryRef<'a, '_, K, Q, V, N>
+{
+    /// Ensure a value is in the entry by inserting the default value if empty,
+    /// and return a mutable reference to the value in the entry.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K: PartialEq, Q: ToOwned<Owned = K> + ?Sized, V, const N: usize>
+    VacantEntryRef<'a, '_, K, Q, V, N>
+{
+    /// Internal function to put the value into the free slot of the entry,
+    /// together with the owned copy of the key.
+    #[inline]
+    fn insert_value(self, v: V) -> &'a mut V {
+        self.map.write_slot(self.index, self.key.to_owned(), v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn does_not_clone_existing_key() {
+        let k = Rc::new("one".to_string());
+        let mut m: Map<Rc<String>, i32, 4> = Map::new();
+        m.insert(Rc::clone(&k), 1);
+        assert_eq!(2, Rc::strong_count(&k));
+        *m.entry_ref(&k).or_insert(0) += 10;
+        assert_eq!(2, Rc::strong_count(&k));
+        assert_eq!(11, m[&k]);
+    }
+
+    #[test]
+    fn clones_absent_key() {
+        let k = Rc::new("one".to_string());
+        let mut m: Map<Rc<String>, i32, 4> = Map::new();
+        *m.entry_ref(&k).or_default() += 10;
+        assert_eq!(2, Rc::strong_count(&k));
+        assert_eq!(10, m[&k]);
+    }
+
+    #[test]
+    fn counts_with_borrowed_keys() {
+        let mut m: Map<String, usize, 4> = Map::new();
+        for w in ["a", "b", "a"] {
+            *m.entry_ref(w).or_insert_with(|| 0) += 1;
+        }
+        assert_eq!(2, m["a"]);
+        assert_eq!(1, m["b"]);
+    }
+}
@@ -19,9 +19,15 @@
 // SOFTWARE.
 
 use crate::Map;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::{BuildHasher, Hash};
 
-impl<K: PartialEq, V: PartialEq, const N: usize> PartialEq for Map<K, V, N> {
-    /// Two maps can be compared.
+impl<K: PartialEq, V: PartialEq, const N: usize, const M: usize> PartialEq<Map<K, V, M>>
+    for Map<K, V, N>
+{
+    /// Two maps can be compared, even if their capacities are different.
     ///
     /// For example:
     ///
@@ -39,15 +45,32 @@ impl<K: PartialEq, V: PartialEq, const N: usize> PartialEq for Map<K, V, N> {
     /// m2.insert(2, 1);
     /// #[cfg(std)]
     /// assert_eq!(m1, m2);
+    /// // two maps of different capacities with the same pairs are equal too:
+    /// let mut m4: micromap::Map<u8, i32, 4> = micromap::Map::new();
+    /// let mut m8: micromap::Map<u8, i32, 8> = micromap::Map::new();
+    /// m4.insert(1, 42);
+    /// m8.insert(1, 42);
+    /// assert!(m4 == m8);
     /// ```
     #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        return self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v));
+    fn eq(&self, other: &Map<K, V, M>) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
     }
 }
 
 impl<K: Eq, V: Eq, const N: usize> Eq for Map<K, V, N> {}
 
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V: PartialEq, S: BuildHasher, const N: usize> PartialEq<HashMap<K, V, S>>
+    for Map<K, V, N>
+{
+    /// A map can be compared with a [`HashMap`] of the same pairs.
+    #[inline]
+    fn eq(&self, other: &HashMap<K, V, S>) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -61,4 +84,31 @@ mod test {
         m2.insert("first".to_string(), 42);
         assert!(m1.eq(&m2));
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn compares_with_hash_map() {
+        let pairs = [(1, "one"), (2, "two"), (3, "three")];
+        let mut m: Map<i32, &str, 4> = Map::from_iter(pairs);
+        let h: HashMap<i32, &str> = HashMap::from(pairs);
+        assert!(m == h);
+        m.remove(&2);
+        assert!(m != h);
+        m.insert(2, "deux");
+        assert!(m != h);
+    }
+
+    #[test]
+    fn compares_maps_of_different_capacities() {
+        let mut m4: Map<i32, i32, 4> = Map::new();
+        let mut m8: Map<i32, i32, 8> = Map::new();
+        m4.insert(1, 1);
+        m4.insert(2, 2);
+        m8.insert(2, 2);
+        m8.insert(1, 1);
+        assert!(m4 == m8);
+        assert!(m8 == m4);
+        m8.insert(3, 3);
+        assert!(m4 != m8);
+    }
 }
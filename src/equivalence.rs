@@ -0,0 +1,69 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+use equivalent::Equivalent;
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Get a reference to a single value, looking it up by a key,
+    /// which is [`Equivalent`] to the keys of the map.
+    #[inline]
+    #[must_use]
+    pub fn get_equivalent<Q: Equivalent<K> + ?Sized>(&self, k: &Q) -> Option<&V> {
+        self.iter().find(|(x, _)| k.equivalent(x)).map(|(_, v)| v)
+    }
+
+    /// Does the map contain a key, which is [`Equivalent`] to this one?
+    #[inline]
+    #[must_use]
+    pub fn contains_equivalent<Q: Equivalent<K> + ?Sized>(&self, k: &Q) -> bool {
+        self.keys().any(|x| k.equivalent(x))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[derive(PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    struct Pair(i32, i32);
+
+    impl Equivalent<Point> for Pair {
+        fn equivalent(&self, key: &Point) -> bool {
+            self.0 == key.x && self.1 == key.y
+        }
+    }
+
+    #[test]
+    fn looks_up_equivalent_keys() {
+        let mut m: Map<Point, &str, 4> = Map::new();
+        m.insert(Point { x: 1, y: 2 }, "one-two");
+        assert_eq!(Some(&"one-two"), m.get_equivalent(&Pair(1, 2)));
+        assert!(m.contains_equivalent(&Pair(1, 2)));
+        assert!(!m.contains_equivalent(&Pair(2, 1)));
+        assert_eq!(None, m.get_equivalent(&Pair(2, 1)));
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{CapacityError, GetManyError, Map};
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+impl<T> Display for CapacityError<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("No more keys available in the map")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for CapacityError<T> {}
+
+impl Display for GetManyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Missing(i) => write!(f, "The key #{i} is absent in the map"),
+            Self::Duplicate(i, j) => write!(f, "The keys #{i} and #{j} are the same"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GetManyError {}
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Insert a single pair into the map, if there is enough space for it.
+    ///
+    /// # Errors
+    ///
+    /// If the key is absent and there is no free slot, the pair is returned
+    /// back in the error, while the map stays untouched.
+    #[inline]
+    pub fn try_insert(&mut self, k: K, v: V) -> Result<(), CapacityError<(K, V)>> {
+        match self.slot_of(&k) {
+            Ok(i) => {
+                *unsafe { self.pairs[i].assume_init_mut() } = Some((k, v));
+            }
+            Err(i) if i < N => {
+                self.write_slot(i, k, v);
+            }
+            Err(_) => return Err(CapacityError { value: (k, v) }),
+        }
+        Ok(())
+    }
+
+    /// Make a map from the pairs of the iterator, if they all fit into it.
+    ///
+    /// # Errors
+    ///
+    /// The first pair, which doesn't fit, is returned in the error. The
+    /// pairs inserted so far are dropped and the rest of the iterator
+    /// is left unvisited.
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = (K, V)>>(
+        iter: I,
+    ) -> Result<Self, CapacityError<(K, V)>> {
+        let mut m = Self::new();
+        for (k, v) in iter {
+            m.try_insert(k, v)?;
+        }
+        Ok(m)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn inserts_while_fits() {
+        let mut m: Map<i32, i32, 2> = Map::new();
+        assert_eq!(Ok(()), m.try_insert(1, 1));
+        assert_eq!(Ok(()), m.try_insert(2, 2));
+        assert_eq!(Ok(()), m.try_insert(2, 22));
+        assert_eq!(Err(CapacityError { value: (3, 3) }), m.try_insert(3, 3));
+        assert_eq!(2, m.len());
+        assert_eq!(22, m[&2]);
+        m.remove(&1);
+        assert_eq!(Ok(()), m.try_insert(3, 3));
+    }
+
+    #[test]
+    fn makes_from_iter_while_fits() {
+        let m: Map<i32, i32, 3> = Map::try_from_iter([(1, 1), (2, 2), (1, 11)]).unwrap();
+        assert_eq!(11, m[&1]);
+        let e = Map::<i32, i32, 2>::try_from_iter((0..5).map(|i| (i, i)));
+        assert_eq!(Some(CapacityError { value: (2, 2) }), e.err());
+    }
+
+    #[test]
+    fn displays_get_many_error() {
+        assert_eq!(
+            "The keys #0 and #2 are the same",
+            format!("{}", GetManyError::Duplicate(0, 2))
+        );
+    }
+
+    #[test]
+    fn displays_error() {
+        let e = CapacityError { value: 42 };
+        assert_eq!("No more keys available in the map", format!("{e}"));
+    }
+}
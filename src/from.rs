@@ -21,8 +21,17 @@
 use crate::Map;
 
 impl<K: PartialEq, V, const N: usize> FromIterator<(K, V)> for Map<K, V, N> {
+    /// Make a map from the pairs of the iterator.
+    ///
+    /// Thanks to this, pairs may also be collected into a `Result<Map, E>`,
+    /// which stops at the first error and drops the pairs collected so far:
+    ///
+    /// ```
+    /// let r: Result<micromap::Map<u8, u8, 4>, &str> =
+    ///     [Ok((1, 1)), Err("oops"), Ok((2, 2))].into_iter().collect();
+    /// assert!(r.is_err());
+    /// ```
     #[inline]
-    #[must_use]
     fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
         let mut m: Self = Self::new();
         for (k, v) in iter {
@@ -32,9 +41,24 @@ impl<K: PartialEq, V, const N: usize> FromIterator<(K, V)> for Map<K, V, N> {
     }
 }
 
-impl<K: PartialEq, V, const N: usize> From<[(K, V); N]> for Map<K, V, N> {
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Make a map from the pairs of the iterator, keeping the first pair
+    /// of every key and ignoring all later pairs with the same key.
     #[inline]
     #[must_use]
+    pub fn from_iter_keep_first<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut m: Self = Self::new();
+        for (k, v) in iter {
+            if !m.contains_key(&k) {
+                m.insert(k, v);
+            }
+        }
+        m
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> From<[(K, V); N]> for Map<K, V, N> {
+    #[inline]
     fn from(arr: [(K, V); N]) -> Self {
         Self::from_iter(arr)
     }
@@ -57,12 +81,29 @@ mod test {
 
     #[test]
     #[should_panic]
-    #[cfg(debug_assertions)]
+    #[allow(clippy::should_panic_without_expect)]
     fn from_larger_iter() {
         let vec = Vec::from(TEST_ARRAY);
         let _m: Map<i32, &str, 1> = Map::from_iter(vec);
     }
 
+    #[test]
+    fn collects_into_result() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let items = vec![
+            Ok((1, Rc::clone(&v))),
+            Ok((2, Rc::clone(&v))),
+            Err("third"),
+            Ok((4, Rc::clone(&v))),
+        ];
+        let r: Result<Map<i32, Rc<()>, 4>, &str> = items.into_iter().collect();
+        assert!(matches!(r, Err("third")));
+        assert_eq!(1, Rc::strong_count(&v));
+        let ok: Result<Map<i32, i32, 4>, &str> = [Ok((1, 1)), Ok((2, 2))].into_iter().collect();
+        assert_eq!(2, ok.unwrap().len());
+    }
+
     #[test]
     fn from_array() {
         let m = Map::from(TEST_ARRAY);
@@ -82,4 +123,11 @@ mod test {
         assert_eq!(m.len(), 3);
         assert_eq!(m[&2], "thu");
     }
+
+    #[test]
+    fn from_iter_keeping_first() {
+        let m: Map<&str, i32, 2> = Map::from_iter_keep_first([("a", 1), ("a", 2), ("b", 3)]);
+        assert_eq!(2, m.len());
+        assert_eq!(1, m["a"]);
+    }
 }
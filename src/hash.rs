@@ -0,0 +1,111 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Set;
+use core::hash::{Hash, Hasher};
+
+impl<T: PartialEq + Hash, const N: usize> Hash for Set<T, N> {
+    /// Hash all values, ignoring their order, so that equal sets
+    /// produce equal hashes.
+    ///
+    /// Every value is hashed separately and the results are added up,
+    /// which doesn't depend on the order of the values.
+    ///
+    /// Pay attention, the values are hashed separately by an internal
+    /// FNV-1a hasher, which is not keyed, and only their sum reaches the
+    /// given hasher. Thus, if two sets collide in FNV-1a, they collide in
+    /// any hasher, even in a keyed one, like the default hasher of
+    /// [`std::collections::HashMap`]. So, a set used as a key of such a map
+    /// doesn't get its protection from collisions crafted on purpose.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut sum: u64 = 0;
+        for k in self {
+            let mut h = Fnv::new();
+            k.hash(&mut h);
+            sum = sum.wrapping_add(h.finish());
+        }
+        state.write_usize(self.len());
+        state.write_u64(sum);
+    }
+}
+
+/// Internal FNV-1a hasher, to hash every value separately.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) struct Fnv(u64);
+
+impl Fnv {
+    pub(crate) const fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= u64::from(*b);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash_of<T: Hash>(t: &T) -> u64 {
+        let mut h = DefaultHasher::new();
+        t.hash(&mut h);
+        h.finish()
+    }
+
+    #[test]
+    fn hashes_sets_regardless_of_order() {
+        let mut a: Set<&str, 4> = Set::new();
+        a.insert("one");
+        a.insert("two");
+        a.insert("three");
+        let mut b: Set<&str, 4> = Set::new();
+        b.insert("three");
+        b.insert("one");
+        b.insert("two");
+        assert_eq!(hash_of(&a), hash_of(&b));
+        b.remove("two");
+        assert_ne!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn hashes_equal_sets_equally() {
+        use std::collections::HashMap;
+        let a: Set<u8, 4> = [1, 2, 3].into_iter().collect();
+        let b: Set<u8, 4> = [3, 2, 1].into_iter().collect();
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        let mut h: HashMap<Set<u8, 4>, &str> = HashMap::new();
+        h.insert(a, "first");
+        assert_eq!(Some(&"first"), h.get(&b));
+    }
+}
@@ -26,7 +26,6 @@ impl<K: Eq + Borrow<Q>, Q: Eq + ?Sized, V, const N: usize> Index<&Q> for Map<K,
     type Output = V;
 
     #[inline]
-    #[must_use]
     fn index(&self, key: &Q) -> &V {
         self.get(key).expect("No entry found for the key")
     }
@@ -34,7 +33,6 @@ impl<K: Eq + Borrow<Q>, Q: Eq + ?Sized, V, const N: usize> Index<&Q> for Map<K,
 
 impl<K: Eq + Borrow<Q>, Q: Eq + ?Sized, V, const N: usize> IndexMut<&Q> for Map<K, V, N> {
     #[inline]
-    #[must_use]
     fn index_mut(&mut self, key: &Q) -> &mut V {
         self.get_mut(key).expect("No entry found for the key")
     }
@@ -61,8 +59,8 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
-    fn wrong_index() -> () {
+    #[should_panic(expected = "No entry found for the key")]
+    fn wrong_index() {
         let mut m: Map<String, i32, 10> = Map::new();
         m.insert("first".to_string(), 42);
         assert_eq!(m["second"], 42);
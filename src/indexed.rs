@@ -0,0 +1,320 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::hash::Fnv;
+use crate::{IndexedMap, Iter, Map};
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+
+impl<K: PartialEq + Hash, V, const N: usize> Default for IndexedMap<K, V, N> {
+    /// Make a default empty [`IndexedMap`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PartialEq + Hash, V, const N: usize> IndexedMap<K, V, N> {
+    /// Make it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            map: Map::new(),
+            index: [0; N],
+        }
+    }
+
+    /// Get its total capacity.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Is it empty?
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.map.next == 0
+    }
+
+    /// Return the total number of pairs inside.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.map.next
+    }
+
+    /// Get the underlying [`Map`], where all pairs are stored without holes.
+    #[inline]
+    #[must_use]
+    pub const fn as_map(&self) -> &Map<K, V, N> {
+        &self.map
+    }
+
+    /// Make an iterator over all pairs, in the order of the slots.
+    #[inline]
+    #[must_use]
+    pub const fn iter(&self) -> Iter<'_, K, V, N> {
+        self.map.iter()
+    }
+
+    /// Does the map contain this key?
+    #[inline]
+    #[must_use]
+    pub fn contains_key<Q: PartialEq + Hash + ?Sized>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.find(k).is_ok()
+    }
+
+    /// Get a reference to a single value.
+    #[inline]
+    #[must_use]
+    pub fn get<Q: PartialEq + Hash + ?Sized>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let b = self.find(k).ok()?;
+        self.map.item(self.index[b] - 1).map(|p| &p.1)
+    }
+
+    /// Get a mutable reference to a single value.
+    #[inline]
+    #[must_use]
+    pub fn get_mut<Q: PartialEq + Hash + ?Sized>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let b = self.find(k).ok()?;
+        unsafe { self.map.pairs[self.index[b] - 1].assume_init_mut() }
+            .as_mut()
+            .map(|p| &mut p.1)
+    }
+
+    /// Insert a single pair into the map.
+    ///
+    /// # Panics
+    ///
+    /// If there is no free slot for a new key, in any mode, always with
+    /// the same message, as if the `checked-insert` feature was enabled.
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) {
+        match self.find(&k) {
+            Ok(b) => {
+                *unsafe { self.map.pairs[self.index[b] - 1].assume_init_mut() } = Some((k, v));
+            }
+            Err(Some(b)) => {
+                let i = self.map.next;
+                self.map.pairs[i].write(Some((k, v)));
+                self.map.next += 1;
+                self.index[b] = i + 1;
+            }
+            Err(None) => panic!("No more keys available in the map"),
+        }
+    }
+
+    /// Remove by key, returning the value, if it was there.
+    ///
+    /// The last pair is moved into the freed slot, to keep the underlying
+    /// [`Map`] without holes.
+    #[inline]
+    pub fn remove<Q: PartialEq + Hash + ?Sized>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        let b = self.find(k).ok()?;
+        let i = self.index[b] - 1;
+        let last = self.map.next - 1;
+        if i != last {
+            self.map.pairs.swap(i, last);
+            if let Some((moved, _)) = self.map.item(i) {
+                let mut m = Self::home(moved);
+                while self.index[m] != last + 1 {
+                    m = (m + 1) % N;
+                }
+                self.index[m] = i + 1;
+            }
+        }
+        let p = unsafe { self.map.pairs[last].assume_init_read() };
+        self.map.next -= 1;
+        self.shift_back(b);
+        p.map(|p| p.1)
+    }
+
+    /// Remove all pairs from it.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.index = [0; N];
+    }
+
+    /// Internal function to find the bucket of the key, or the first empty
+    /// bucket, where it may be put, if it is absent and there is one.
+    #[inline]
+    fn find<Q: PartialEq + Hash + ?Sized>(&self, k: &Q) -> Result<usize, Option<usize>>
+    where
+        K: Borrow<Q>,
+    {
+        if N == 0 {
+            return Err(None);
+        }
+        let mut b = Self::home(k);
+        for _ in 0..N {
+            let e = self.index[b];
+            if e == 0 {
+                return Err(Some(b));
+            }
+            if self.map.item(e - 1).is_some_and(|p| p.0.borrow() == k) {
+                return Ok(b);
+            }
+            b = (b + 1) % N;
+        }
+        Err(None)
+    }
+
+    /// Internal function to clear the bucket and move the buckets after it
+    /// backwards, so that no key becomes unreachable from its home bucket.
+    #[inline]
+    fn shift_back(&mut self, mut i: usize) {
+        let mut j = i;
+        loop {
+            self.index[i] = 0;
+            loop {
+                j = (j + 1) % N;
+                let e = self.index[j];
+                if e == 0 {
+                    return;
+                }
+                let stays = self.map.item(e - 1).is_some_and(|p| {
+                    let h = Self::home(&p.0);
+                    if i <= j {
+                        i < h && h <= j
+                    } else {
+                        i < h || h <= j
+                    }
+                });
+                if !stays {
+                    break;
+                }
+            }
+            self.index[i] = self.index[j];
+            i = j;
+        }
+    }
+
+    /// Internal function to get the home bucket of the key.
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn home<Q: Hash + ?Sized>(k: &Q) -> usize {
+        let mut h = Fnv::new();
+        k.hash(&mut h);
+        (h.finish() % N as u64) as usize
+    }
+}
+
+impl<'a, K: PartialEq + Hash, V, const N: usize> IntoIterator for &'a IndexedMap<K, V, N> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn inserts_and_gets() {
+        let mut m: IndexedMap<u32, u32, 16> = IndexedMap::new();
+        for i in 0..16 {
+            m.insert(i, i * 2);
+        }
+        m.insert(7, 42);
+        assert_eq!(16, m.len());
+        assert_eq!(Some(&42), m.get(&7));
+        assert_eq!(Some(&30), m.get(&15));
+        assert!(!m.contains_key(&16));
+        *m.get_mut(&3).unwrap() += 1;
+        assert_eq!(Some(&7), m.get(&3));
+    }
+
+    #[test]
+    #[should_panic(expected = "No more keys available in the map")]
+    fn refuses_to_insert_past_capacity() {
+        let mut m: IndexedMap<u32, u32, 2> = IndexedMap::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.insert(2, 22);
+        m.insert(3, 3);
+    }
+
+    #[test]
+    fn removes_keeping_keys_reachable() {
+        let mut m: IndexedMap<String, usize, 8> = IndexedMap::new();
+        for i in 0..8 {
+            m.insert(i.to_string(), i);
+        }
+        assert_eq!(Some(2), m.remove("2"));
+        assert_eq!(None, m.remove("2"));
+        assert_eq!(7, m.len());
+        assert!(m.as_map().is_compact());
+        for i in (0..8).filter(|i| *i != 2) {
+            assert_eq!(Some(&i), m.get(i.to_string().as_str()));
+        }
+        m.clear();
+        assert!(m.is_empty());
+        assert!(!m.contains_key("1"));
+    }
+
+    #[test]
+    fn behaves_like_plain_map() {
+        let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut rnd = move |n: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % n
+        };
+        let mut m: Map<u64, u64, 8> = Map::new();
+        let mut x: IndexedMap<u64, u64, 8> = IndexedMap::new();
+        for step in 0..10_000 {
+            let k = rnd(12);
+            if rnd(2) == 0 {
+                if m.contains_key(&k) || m.len() < 8 {
+                    m.insert(k, step);
+                    x.insert(k, step);
+                }
+            } else {
+                assert_eq!(m.remove_entry(&k).map(|p| p.1), x.remove(&k));
+            }
+            assert_eq!(m.len(), x.len());
+            for k in 0..12 {
+                assert_eq!(m.get(&k), x.get(&k));
+            }
+        }
+    }
+}
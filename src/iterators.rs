@@ -19,14 +19,16 @@
 // SOFTWARE.
 
 use crate::{IntoIter, Iter, IterMut, Map};
+use core::borrow::Borrow;
+use core::iter::FusedIterator;
 use core::mem;
 use core::mem::MaybeUninit;
 
 impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
-    /// Make an iterator over all pairs.
+    /// Make an iterator over all pairs, in the order of the slots.
     #[inline]
     #[must_use]
-    pub const fn iter(&self) -> Iter<K, V, N> {
+    pub const fn iter(&self) -> Iter<'_, K, V, N> {
         Iter {
             next: self.next,
             pos: 0,
@@ -34,22 +36,78 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         }
     }
 
+    /// Make an iterator over the pairs after the given key, in the order
+    /// of the slots.
+    ///
+    /// If the key is absent, all pairs are visited, like [`Map::iter`] does.
+    #[inline]
+    #[must_use]
+    pub fn iter_from<Q: PartialEq + ?Sized>(&self, start: &Q) -> Iter<'_, K, V, N>
+    where
+        K: Borrow<Q>,
+    {
+        Iter {
+            next: self.next,
+            pos: self.slot_of(start).map_or(0, |i| i + 1),
+            pairs: &self.pairs,
+        }
+    }
+
     /// An iterator with mutable references to the values but
+    /// immutable references to the keys, in the order of the slots.
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<K, V> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
         IterMut {
             next: self.next,
             pos: 0,
             iter: self.pairs.iter_mut(),
         }
     }
+
+    /// An iterator with mutable references to the values, like
+    /// [`Map::iter_mut`] makes, which also gives the position of every
+    /// pair in the internal array.
+    #[inline]
+    pub fn iter_mut_indexed(&mut self) -> impl Iterator<Item = (usize, &K, &mut V)> {
+        self.pairs[..self.next]
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(i, p)| {
+                unsafe { p.assume_init_mut() }
+                    .as_mut()
+                    .map(|p| (i, &p.0, &mut p.1))
+            })
+    }
+
+    /// Make an iterator over all pairs of this map and then over all pairs
+    /// of the other one.
+    ///
+    /// The keys are not deduplicated, so a key present in both maps
+    /// is visited twice.
+    #[inline]
+    pub fn chain<'a, const M: usize>(
+        &'a self,
+        other: &'a Map<K, V, M>,
+    ) -> impl Iterator<Item = (&'a K, &'a V)> {
+        self.iter().chain(other.iter())
+    }
+
+    /// Make an iterator over the keys present in both maps, together with
+    /// the values from this map and from the other one.
+    #[inline]
+    pub fn zip_values<'a, const M: usize>(
+        &'a self,
+        other: &'a Map<K, V, M>,
+    ) -> impl Iterator<Item = (&'a K, &'a V, &'a V)> {
+        self.iter()
+            .filter_map(move |(k, v)| other.get(k).map(|o| (k, v, o)))
+    }
 }
 
 impl<'a, K, V, const N: usize> Iterator for Iter<'a, K, V, N> {
     type Item = (&'a K, &'a V);
 
     #[inline]
-    #[must_use]
     fn next(&mut self) -> Option<Self::Item> {
         while self.pos < self.next {
             let p = unsafe { self.pairs[self.pos].assume_init_ref() };
@@ -60,6 +118,30 @@ impl<'a, K, V, const N: usize> Iterator for Iter<'a, K, V, N> {
         }
         None
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.pairs[self.pos..self.next]
+            .iter()
+            .filter(|p| unsafe { p.assume_init_ref() }.is_some())
+            .count();
+        (len, Some(len))
+    }
+}
+
+impl<K, V, const N: usize> ExactSizeIterator for Iter<'_, K, V, N> {}
+
+impl<K, V, const N: usize> FusedIterator for Iter<'_, K, V, N> {}
+
+impl<K, V, const N: usize> Clone for Iter<'_, K, V, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            pos: self.pos,
+            pairs: self.pairs,
+        }
+    }
 }
 
 impl<'a, K, V> Iterator for IterMut<'a, K, V> {
@@ -76,13 +158,25 @@ impl<'a, K, V> Iterator for IterMut<'a, K, V> {
         }
         None
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.as_slice()[..self.next - self.pos]
+            .iter()
+            .filter(|p| unsafe { p.assume_init_ref() }.is_some())
+            .count();
+        (len, Some(len))
+    }
 }
 
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
 impl<K: PartialEq, V, const N: usize> Iterator for IntoIter<K, V, N> {
     type Item = (K, V);
 
     #[inline]
-    #[must_use]
     fn next(&mut self) -> Option<Self::Item> {
         while self.pos < self.map.next {
             let p = &mut self.map.pairs[self.pos];
@@ -95,6 +189,47 @@ impl<K: PartialEq, V, const N: usize> Iterator for IntoIter<K, V, N> {
         }
         None
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.pos..self.map.next)
+            .filter(|&i| self.map.item(i).is_some())
+            .count();
+        (len, Some(len))
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.pos < self.map.next {
+            self.map.next -= 1;
+            self.map.check_invariants();
+            let p = &mut self.map.pairs[self.map.next];
+            unsafe {
+                if p.assume_init_ref().is_some() {
+                    return mem::replace(p, MaybeUninit::new(None)).assume_init();
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {}
+
+impl<K: PartialEq, V, const N: usize> FusedIterator for IntoIter<K, V, N> {}
+
+impl<K: PartialEq, V, const N: usize> IntoIter<K, V, N> {
+    /// Get the pair, which will be returned by the next call
+    /// of `next()`, without advancing the iterator.
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> Option<(&K, &V)> {
+        (self.pos..self.map.next)
+            .find_map(|i| self.map.item(i))
+            .map(|p| (&p.0, &p.1))
+    }
 }
 
 impl<'a, K: PartialEq, V, const N: usize> IntoIterator for &'a Map<K, V, N> {
@@ -102,7 +237,6 @@ impl<'a, K: PartialEq, V, const N: usize> IntoIterator for &'a Map<K, V, N> {
     type IntoIter = Iter<'a, K, V, N>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -123,7 +257,6 @@ impl<K: PartialEq, V, const N: usize> IntoIterator for Map<K, V, N> {
     type IntoIter = IntoIter<K, V, N>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         IntoIter { pos: 0, map: self }
     }
@@ -155,7 +288,7 @@ mod test {
         m.insert("one".to_string(), 42);
         m.insert("two".to_string(), 16);
         let mut sum = 0;
-        for (_k, v) in m.iter() {
+        for (_k, v) in &m {
             sum += v;
         }
         assert_eq!(58, sum);
@@ -173,6 +306,22 @@ mod test {
         assert_eq!(58, sum);
     }
 
+    #[test]
+    fn clones_iterator_mid_way() {
+        let mut m: Map<i32, i32, 10> = Map::new();
+        for i in 0..4 {
+            m.insert(i, i);
+        }
+        let mut it = m.iter();
+        it.next();
+        let mut fork = it.clone();
+        assert_eq!(Some((&1, &1)), fork.next());
+        assert_eq!(Some((&2, &2)), fork.next());
+        assert_eq!(Some((&1, &1)), it.next());
+        assert_eq!(2, it.count());
+        assert_eq!(1, fork.count());
+    }
+
     #[test]
     fn iterate_with_blanks() {
         let mut m: Map<String, i32, 10> = Map::new();
@@ -181,12 +330,27 @@ mod test {
         m.insert("three".to_string(), 5);
         m.remove("two");
         let mut sum = 0;
-        for (_k, v) in m.iter() {
+        for (_k, v) in &m {
             sum += v;
         }
         assert_eq!(6, sum);
     }
 
+    #[test]
+    fn peeks_into_iterator() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 1);
+        m.insert("two".to_string(), 2);
+        m.insert("three".to_string(), 3);
+        m.remove("two");
+        let mut it = m.into_iter();
+        assert_eq!(Some((&"one".to_string(), &1)), it.peek());
+        assert_eq!(Some(("one".to_string(), 1)), it.next());
+        assert_eq!(Some((&"three".to_string(), &3)), it.peek());
+        assert_eq!(Some(("three".to_string(), 3)), it.next());
+        assert_eq!(None, it.peek());
+    }
+
     #[test]
     fn into_iterate_with_blanks() {
         let mut m: Map<String, i32, 10> = Map::new();
@@ -207,9 +371,7 @@ mod test {
         m.insert("one".to_string(), 2);
         m.insert("two".to_string(), 3);
         m.insert("three".to_string(), 5);
-        for (_k, v) in m.iter_mut() {
-            *v *= 2;
-        }
+        m.iter_mut().for_each(|(_k, v)| *v *= 2);
         let sum = m.iter().map(|p| p.1).sum::<i32>();
         assert_eq!(20, sum);
     }
@@ -240,16 +402,164 @@ mod test {
     }
 
     #[test]
-    fn into_iter_drop() {
+    fn zips_values_of_shared_keys() {
+        let mut a: Map<&str, i32, 4> = Map::new();
+        a.insert("x", 1);
+        a.insert("y", 2);
+        a.insert("z", 3);
+        let mut b: Map<&str, i32, 8> = Map::new();
+        b.insert("z", 30);
+        b.insert("w", 40);
+        b.insert("x", 10);
+        assert_eq!(
+            a.zip_values(&b).collect::<Vec<_>>(),
+            [(&"x", &1, &10), (&"z", &3, &30)]
+        );
+    }
+
+    #[test]
+    fn iterates_in_insertion_order() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i * 10);
+        }
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+        m.insert(2, 22);
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+        m.remove(&1);
+        m.remove(&3);
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 2, 4]);
+        m.insert(3, 33);
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 3, 2, 4]);
+        m.insert(5, 55);
+        m.insert(6, 66);
+        assert_eq!(
+            m.into_iter().collect::<Vec<_>>(),
+            [(0, 0), (3, 33), (2, 22), (5, 55), (4, 40), (6, 66)]
+        );
+    }
+
+    #[test]
+    fn into_iterates_backwards() {
+        let mut m: Map<i32, i32, 10> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i * 10);
+        }
+        m.remove(&4);
+        assert_eq!(
+            m.into_iter().rev().collect::<Vec<_>>(),
+            [(3, 30), (2, 20), (1, 10), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn into_iterates_from_both_ends() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, Rc::clone(&v));
+        }
+        m.remove(&1);
+        let mut it = m.into_iter();
+        assert_eq!(0, it.next().unwrap().0);
+        assert_eq!(5, it.next_back().unwrap().0);
+        assert_eq!(2, it.next().unwrap().0);
+        assert_eq!(2, it.len());
+        assert_eq!(4, it.next_back().unwrap().0);
+        assert_eq!(3, it.next_back().unwrap().0);
+        assert!(it.next().is_none());
+        assert!(it.next_back().is_none());
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn drops_rest_after_iterating_backwards() {
         use std::rc::Rc;
+        let v = Rc::new(());
         let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        for i in 0..8 {
+            m.insert(i, Rc::clone(&v));
+        }
+        let mut it = m.into_iter();
+        it.next_back();
+        it.next();
+        assert_eq!(7, Rc::strong_count(&v));
+        drop(it);
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn drops_rest_of_into_iter() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        for i in 0..8 {
+            m.insert(i, Rc::clone(&v));
+        }
+        let mut it = m.into_iter();
+        it.next();
+        it.next();
+        assert_eq!(7, Rc::strong_count(&v));
+        drop(it);
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn into_iter_drop() {
+        use std::rc::Rc;
+        let mut m: Map<usize, Rc<()>, 8> = Map::new();
         let v = Rc::new(());
         let n = 8;
         for i in 0..n {
             m.insert(i, Rc::clone(&v));
         }
-        assert_eq!(Rc::strong_count(&v), (n + 1) as usize);
+        assert_eq!(Rc::strong_count(&v), n + 1);
         let _p = m.into_iter().nth(3);
         assert_eq!(Rc::strong_count(&v), 2); // v & p
     }
+
+    #[test]
+    fn iterates_mutably_with_indices() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i);
+        }
+        m.remove(&1);
+        m.remove(&3);
+        let mut seen = Vec::new();
+        for (i, k, v) in m.iter_mut_indexed() {
+            *v += 10;
+            seen.push((i, *k));
+        }
+        assert_eq!(3, seen.len());
+        for (i, k) in seen {
+            assert_eq!(Ok(i), m.slot_of(&k));
+        }
+        assert_eq!(14, m[&4]);
+    }
+
+    #[test]
+    fn iterates_from_key() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i * 10);
+        }
+        m.remove(&3);
+        let rest: Vec<_> = m.iter_from(&1).collect();
+        assert_eq!(vec![(&2, &20), (&4, &40)], rest);
+        assert_eq!(4, m.iter_from(&42).count());
+        assert_eq!(0, m.iter_from(&4).len());
+    }
+
+    #[test]
+    fn chains_two_maps() {
+        let mut a: Map<&str, i32, 4> = Map::new();
+        a.insert("x", 1);
+        a.insert("y", 2);
+        let mut b: Map<&str, i32, 2> = Map::new();
+        b.insert("y", 3);
+        let all: Vec<_> = a.chain(&b).collect();
+        assert_eq!(vec![(&"x", &1), (&"y", &2), (&"y", &3)], all);
+    }
 }
@@ -19,15 +19,16 @@
 // SOFTWARE.
 
 use crate::{IntoKeys, Keys, Map};
+use core::iter::FusedIterator;
 
 impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
-    /// An iterator visiting all keys in arbitrary order.
+    /// An iterator visiting all keys in the order of the slots.
     #[inline]
     pub const fn keys(&self) -> Keys<'_, K, V, N> {
         Keys { iter: self.iter() }
     }
 
-    /// Consuming iterator visiting all keys in arbitrary order.
+    /// Consuming iterator visiting all keys in the order of the slots.
     #[inline]
     pub fn into_keys(self) -> IntoKeys<K, V, N> {
         IntoKeys {
@@ -43,8 +44,17 @@ impl<'a, K: PartialEq, V, const N: usize> Iterator for Keys<'a, K, V, N> {
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|p| p.0)
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
+impl<K: PartialEq, V, const N: usize> ExactSizeIterator for Keys<'_, K, V, N> {}
+
+impl<K: PartialEq, V, const N: usize> FusedIterator for Keys<'_, K, V, N> {}
+
 impl<K: PartialEq, V, const N: usize> Iterator for IntoKeys<K, V, N> {
     type Item = K;
 
@@ -77,4 +87,17 @@ mod test {
             ["foo".to_string(), "bar".to_string()]
         );
     }
+
+    #[test]
+    fn keys_know_their_length() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.insert(3, 3);
+        m.remove(&2);
+        let mut keys = m.keys();
+        assert_eq!(m.len(), keys.len());
+        keys.next();
+        assert_eq!(1, keys.len());
+    }
 }
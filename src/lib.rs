@@ -44,26 +44,56 @@
 //! to a panic.
 
 #![cfg_attr(all(not(feature = "std"), not(doc), not(test)), no_std)]
+#![cfg_attr(unstable_const, feature(generic_const_exprs))]
+#![cfg_attr(unstable_const, allow(incomplete_features))]
 #![doc(html_root_url = "https://docs.rs/micromap/0.0.0")]
 #![deny(warnings)]
 #![warn(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo)]
 #![allow(clippy::multiple_inherent_impl)]
 #![allow(clippy::multiple_crate_versions)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(feature = "std")]
 mod debug;
 
 mod clone;
+#[cfg(unstable_const)]
+mod concat;
+#[cfg(feature = "std")]
+mod conversions;
 mod ctors;
+mod diff;
+mod display;
+mod drain_filter;
+mod entry;
+#[cfg(feature = "alloc")]
+mod entry_ref;
 mod eq;
+#[cfg(feature = "equivalent")]
+mod equivalence;
+mod error;
 mod from;
+mod hash;
 mod index;
+#[cfg(feature = "indexed")]
+mod indexed;
 mod iterators;
 mod keys;
 mod map;
+#[cfg(feature = "nanoserde")]
+mod nanoserde_bin;
+#[cfg(feature = "rayon")]
+mod parallel;
 #[cfg(feature = "serde")]
 mod serialization;
+mod set;
 mod values;
+#[cfg(feature = "alloc")]
+mod vectors;
+#[cfg(feature = "zeroize")]
+mod zeroing;
 
 use core::mem::MaybeUninit;
 
@@ -89,10 +119,34 @@ use core::mem::MaybeUninit;
 ///
 /// It is also faster because it doesn't grow in size. When a [`Map`] is created,
 /// its size is fixed on stack. If an attempt is made to insert too many keys
-/// into it, it simply panics. Moreover, in the "release" mode it doesn't panic,
-/// but its behaviour is undefined. In the "release" mode all boundary checks
-/// are disabled, for the sake of higher performance.
-pub struct Map<K: PartialEq, V, const N: usize> {
+/// into it, it simply panics, in the "release" mode too. However, in the "release"
+/// mode the explicit check of the capacity is skipped, for the sake of higher
+/// performance, unless the `checked-insert` feature is enabled, and the panic
+/// comes from the boundary check of the internal array, with a less helpful message.
+///
+/// All iterators visit the pairs in the order of their slots in the array.
+/// A new key takes the first slot freed by a removal, if there is one,
+/// or the next unused slot otherwise. Thus, until something is removed,
+/// the pairs are visited in the order of insertion:
+///
+/// ```
+/// let mut m : micromap::Map<u64, &str, 8> = micromap::Map::new();
+/// m.insert(1, "one");
+/// m.insert(2, "two");
+/// m.insert(3, "three");
+/// m.remove(&1);
+/// m.insert(4, "four");
+/// assert_eq!(vec![&4, &2, &3], m.keys().collect::<Vec<_>>());
+/// ```
+///
+/// A [`Map`] is [`Send`] and [`Sync`] if its keys and values are, so it can't
+/// be sent to another thread, for example, if its values are [`std::rc::Rc`]:
+///
+/// ```compile_fail
+/// fn assert_send<T: Send>() {}
+/// assert_send::<micromap::Map<u8, std::rc::Rc<()>, 8>>();
+/// ```
+pub struct Map<K, V, const N: usize> {
     /// The next available pair in the array.
     next: usize,
     /// The fixed-size array of key-value pairs.
@@ -146,3 +200,134 @@ pub struct Keys<'a, K: PartialEq, V, const N: usize> {
 pub struct IntoKeys<K: PartialEq, V, const N: usize> {
     iter: IntoIter<K, V, N>,
 }
+
+/// A [`Map`] with a hash index on top of it, for faster lookups.
+///
+/// The index is an open-addressed table of `N` buckets, each holding the
+/// position of a pair in the [`Map`], with linear probing. The price is `N`
+/// more words of memory, and the need to hash every key on every operation,
+/// which only pays off for larger maps. Still, nothing is allocated on the heap.
+///
+/// Since there are as many buckets as slots, lookups degrade as the map
+/// fills up: while it is at most half full, a lookup checks a few buckets
+/// on average, but when it is nearly full, a lookup of an absent key may
+/// check all `N` buckets, which is as slow as a plain [`Map`]. Thus, it's
+/// better to make it about twice as large as the number of pairs expected.
+///
+/// ```
+/// let mut m: micromap::IndexedMap<u64, &str, 64> = micromap::IndexedMap::new();
+/// m.insert(42, "answer");
+/// assert_eq!(Some(&"answer"), m.get(&42));
+/// ```
+#[cfg(feature = "indexed")]
+pub struct IndexedMap<K, V, const N: usize> {
+    map: Map<K, V, N>,
+    index: [usize; N],
+}
+
+/// Iterator removing the pairs of the [`Map`], which match the predicate.
+///
+/// If it is dropped before the end, the remaining matching pairs
+/// are removed anyway.
+pub struct DrainFilter<'a, K: PartialEq, V, const N: usize, F: FnMut(&K, &mut V) -> bool> {
+    pos: usize,
+    map: &'a mut Map<K, V, N>,
+    pred: F,
+}
+
+/// A set of unique values, backed by a [`Map`] without values.
+///
+/// For example, this is how you make a set, which is allocated on stack and is capable of storing
+/// up to eight values:
+///
+/// ```
+/// let mut s : micromap::Set<u64, 8> = micromap::Set::new();
+/// s.insert(1);
+/// s.insert(2);
+/// s.insert(1);
+/// assert_eq!(2, s.len());
+/// ```
+pub struct Set<T: PartialEq, const N: usize> {
+    map: Map<T, (), N>,
+}
+
+/// Iterator over the [`Set`].
+pub struct SetIter<'a, T: PartialEq, const N: usize> {
+    iter: Keys<'a, T, (), N>,
+}
+
+/// A view into a single entry in a [`Map`], which may either be vacant or occupied.
+///
+/// This `enum` is constructed from the [`Map::entry`] method.
+pub enum Entry<'a, K: PartialEq, V, const N: usize> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V, N>),
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, K, V, N>),
+}
+
+/// A view into an occupied entry in a [`Map`]. It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, K: PartialEq, V, const N: usize> {
+    /// The position of the pair in the array.
+    index: usize,
+    map: &'a mut Map<K, V, N>,
+}
+
+/// A view into a vacant entry in a [`Map`]. It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, K: PartialEq, V, const N: usize> {
+    key: K,
+    /// The position in the array, where the pair will be inserted.
+    index: usize,
+    map: &'a mut Map<K, V, N>,
+}
+
+/// A single difference between two [`Map`]s, produced by [`Map::diff`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change<'a, K, V> {
+    /// The key is only in the other map.
+    Added(&'a K, &'a V),
+    /// The key is only in this map.
+    Removed(&'a K, &'a V),
+    /// The key is in both maps, with the old and the new value.
+    Modified(&'a K, &'a V, &'a V),
+}
+
+/// A view into a single entry in a [`Map`], which may either be vacant or occupied,
+/// with a borrowed key.
+///
+/// This `enum` is constructed from the [`Map::entry_ref`] method.
+#[cfg(feature = "alloc")]
+pub enum EntryRef<'a, 'b, K: PartialEq, Q: ?Sized, V, const N: usize> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V, N>),
+    /// A vacant entry.
+    Vacant(VacantEntryRef<'a, 'b, K, Q, V, N>),
+}
+
+/// A view into a vacant entry in a [`Map`], with a borrowed key.
+/// It is part of the [`EntryRef`] enum.
+#[cfg(feature = "alloc")]
+pub struct VacantEntryRef<'a, 'b, K: PartialEq, Q: ?Sized, V, const N: usize> {
+    key: &'b Q,
+    /// The position in the array, where the pair will be inserted.
+    index: usize,
+    map: &'a mut Map<K, V, N>,
+}
+
+/// An error of an attempt to put more pairs into a [`Map`] than it can fit.
+///
+/// The rejected element, if any, is returned back in the `value`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T = ()> {
+    /// The element, which didn't fit.
+    pub value: T,
+}
+
+/// An error of an attempt to get many mutable values of a [`Map`] at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GetManyError {
+    /// The key at this position among the requested ones is absent.
+    Missing(usize),
+    /// The keys at these positions among the requested ones are the same.
+    Duplicate(usize, usize),
+}
@@ -18,18 +18,32 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::Map;
+use crate::{GetManyError, Map, OccupiedEntry};
 use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::mem;
 use core::mem::MaybeUninit;
 
-impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+impl<K, V, const N: usize> Map<K, V, N> {
     /// Get its total capacity.
     #[inline]
     #[must_use]
     pub const fn capacity(&self) -> usize {
         N
     }
+}
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Get the number of pairs that can surely be inserted, in the worst case.
+    ///
+    /// This counts only the never used slots at the end of the array. The
+    /// slots freed by removals are not counted, even though they are reused
+    /// by insertions too, so more pairs may actually fit.
+    #[inline]
+    #[must_use]
+    pub const fn max_additional(&self) -> usize {
+        N - self.next
+    }
 
     /// Is it empty?
     #[inline]
@@ -51,6 +65,31 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         busy
     }
 
+    /// Get the position in the internal array, where the next new key
+    /// would be inserted: the earliest free slot, if there is one.
+    ///
+    /// Returns `None` if the map is full.
+    #[inline]
+    #[must_use]
+    pub fn next_free_index(&self) -> Option<usize> {
+        (0..self.next)
+            .find(|&i| self.item(i).is_none())
+            .or_else(|| (self.next < N).then_some(self.next))
+    }
+
+    /// Get the share of the capacity occupied by pairs, from `0.0` to `1.0`.
+    ///
+    /// A map with zero capacity has zero load.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn load(&self) -> f32 {
+        if N == 0 {
+            return 0.0;
+        }
+        self.len() as f32 / N as f32
+    }
+
     /// Does the map contain this key?
     #[inline]
     #[must_use]
@@ -68,6 +107,30 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         false
     }
 
+    /// Does the map contain all these keys?
+    ///
+    /// It is `true` if there are no keys at all.
+    #[inline]
+    #[must_use]
+    pub fn contains_all<Q: PartialEq, I: IntoIterator<Item = Q>>(&self, keys: I) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        keys.into_iter().all(|k| self.contains_key(&k))
+    }
+
+    /// Does the map contain any of these keys?
+    ///
+    /// It is `false` if there are no keys at all.
+    #[inline]
+    #[must_use]
+    pub fn contains_any<Q: PartialEq, I: IntoIterator<Item = Q>>(&self, keys: I) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        keys.into_iter().any(|k| self.contains_key(&k))
+    }
+
     /// Remove by key.
     #[inline]
     pub fn remove<Q: PartialEq + ?Sized>(&mut self, k: &Q)
@@ -83,30 +146,113 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
                 }
             }
         }
+        self.check_invariants();
+    }
+
+    /// Remove all given keys and return the number of them actually removed.
+    #[inline]
+    pub fn remove_all<Q: PartialEq, I: IntoIterator<Item = Q>>(&mut self, keys: I) -> usize
+    where
+        K: Borrow<Q>,
+    {
+        keys.into_iter()
+            .filter(|k| self.remove_entry(k).is_some())
+            .count()
+    }
+
+    /// Remove by key, returning the value, and move the last pair
+    /// into the freed slot.
+    ///
+    /// This breaks the order of the pairs, but doesn't leave a new hole
+    /// in the internal array, which makes later scans faster. The holes left
+    /// by earlier removals stay where they are, so the array is dense
+    /// afterwards only if it was dense before.
+    #[inline]
+    pub fn swap_remove<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        let i = self.index_of(k)?;
+        let (_, v) = unsafe { self.pairs[i].assume_init_mut() }.take()?;
+        if let Some(j) = (i + 1..self.next).rfind(|&j| self.item(j).is_some()) {
+            self.pairs.swap(i, j);
+        }
+        while self.next > 0 && self.item(self.next - 1).is_none() {
+            self.next -= 1;
+        }
+        self.check_trimmed();
+        Some(v)
+    }
+
+    /// Remove the pair at the given position of the internal array, returning it.
+    ///
+    /// If the slot is empty or is out of range, `None` is returned.
+    #[inline]
+    pub const fn remove_index(&mut self, i: usize) -> Option<(K, V)> {
+        if i >= self.next || self.item(i).is_none() {
+            return None;
+        }
+        let ret = mem::replace(&mut self.pairs[i], MaybeUninit::new(None));
+        while self.next > 0 && self.item(self.next - 1).is_none() {
+            self.next -= 1;
+        }
+        self.check_trimmed();
+        unsafe { ret.assume_init() }
     }
 
     /// Insert a single pair into the map.
     ///
     /// # Panics
     ///
-    /// It may panic if there are too many pairs in the map already. Pay attention,
-    /// it panics only in the "debug" mode. In the "release" mode, you are going to get
-    /// undefined behavior. This is done for the sake of performance, in order to
-    /// avoid a repetitive check for the boundary condition on every `insert()`.
+    /// It panics if there are too many pairs in the map already, in any mode,
+    /// because the internal array is never written past its end. In the "release"
+    /// mode, the explicit check of the capacity is skipped, for the sake of
+    /// performance, and the panic comes from the boundary check of the array,
+    /// with a less helpful message. If you want the explicit check, with the
+    /// "No more keys available in the map" message, in the "release" mode too,
+    /// enable the `checked-insert` feature.
     #[inline]
     pub fn insert(&mut self, k: K, v: V) {
+        self.insert_checked(k, v);
+    }
+
+    /// Insert a single pair into the map, right after the last used slot,
+    /// without checking whether the key is already there.
+    ///
+    /// # Safety
+    ///
+    /// The key must be absent in the map and there must be a free slot
+    /// right after the last used one, otherwise the behavior is undefined.
+    #[inline]
+    pub unsafe fn insert_unique_unchecked(&mut self, k: K, v: V) {
+        debug_assert!(self.next < N, "No more keys available in the map");
+        self.pairs.get_unchecked_mut(self.next).write(Some((k, v)));
+        self.next += 1;
+        self.check_invariants();
+    }
+
+    /// Insert a single pair into the map, returning `true` if the key is new,
+    /// or `false` if the value of the existing key was overwritten.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are too many pairs in the map, exactly like
+    /// [`Map::insert`] does.
+    #[inline]
+    pub fn insert_checked(&mut self, k: K, v: V) -> bool {
         let mut target = self.next;
+        let mut fresh = true;
         let mut i = 0;
         loop {
             if i == self.next {
-                #[cfg(feature = "std")]
-                debug_assert!(target < N, "No more keys available in the map");
+                Self::check_slot(target);
                 break;
             }
             match self.item(i) {
                 Some(p) => {
                     if p.0 == k {
                         target = i;
+                        fresh = false;
                         unsafe {
                             self.pairs[i].assume_init_drop();
                         }
@@ -114,7 +260,9 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
                     }
                 }
                 None => {
-                    target = i;
+                    if target == self.next {
+                        target = i;
+                    }
                 }
             }
             i += 1;
@@ -123,6 +271,94 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         if target == self.next {
             self.next += 1;
         }
+        self.check_invariants();
+        fresh
+    }
+
+    /// Insert all pairs from the slice, copying them.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are too many pairs in the map, exactly like
+    /// [`Map::insert`] does.
+    #[inline]
+    pub fn extend_from_slice(&mut self, slice: &[(K, V)])
+    where
+        K: Copy,
+        V: Copy,
+    {
+        for &(k, v) in slice {
+            self.insert(k, v);
+        }
+        self.check_invariants();
+    }
+
+    /// Move all pairs from another map into this one, combining the values
+    /// of the keys that are present in both maps with the resolver.
+    ///
+    /// The resolver gets the key, the existing value, and the incoming one.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are too many pairs in the map, exactly like
+    /// [`Map::insert`] does.
+    #[inline]
+    pub fn merge_with<const M: usize, F: FnMut(&K, &mut V, V)>(
+        &mut self,
+        other: Map<K, V, M>,
+        mut resolver: F,
+    ) {
+        for (k, v) in other {
+            match self.slot_of(&k) {
+                Ok(index) => resolver(&k, OccupiedEntry { index, map: self }.into_mut(), v),
+                Err(index) => {
+                    self.write_slot(index, k, v);
+                }
+            }
+        }
+    }
+
+    /// Move all pairs into two new maps: the pairs matching the predicate
+    /// go to the left one, all others go to the right one.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are too many pairs for the capacity of any
+    /// of the new maps, exactly like [`Map::insert`] does.
+    #[inline]
+    pub fn partition<const A: usize, const B: usize, F: FnMut(&K, &V) -> bool>(
+        self,
+        mut pred: F,
+    ) -> (Map<K, V, A>, Map<K, V, B>) {
+        let mut left: Map<K, V, A> = Map::new();
+        let mut right: Map<K, V, B> = Map::new();
+        for (k, v) in self {
+            if pred(&k, &v) {
+                Map::<K, V, A>::check_slot(left.next);
+                left.pairs[left.next].write(Some((k, v)));
+                left.next += 1;
+            } else {
+                Map::<K, V, B>::check_slot(right.next);
+                right.pairs[right.next].write(Some((k, v)));
+                right.next += 1;
+            }
+        }
+        (left, right)
+    }
+
+    /// Replace the value of the key, only if the key is already in the map.
+    ///
+    /// Returns the old value, or the pair back, if the key is absent.
+    ///
+    /// # Errors
+    ///
+    /// If the key is absent, the pair is returned back untouched.
+    #[inline]
+    pub fn replace(&mut self, k: K, v: V) -> Result<V, (K, V)> {
+        match self.get_mut(&k) {
+            Some(old) => Ok(mem::replace(old, v)),
+            None => Err((k, v)),
+        }
     }
 
     /// Get a reference to a single value.
@@ -142,53 +378,514 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         None
     }
 
+    /// Get a reference to a single value, or the default one if the key is absent.
+    #[inline]
+    #[must_use]
+    pub fn get_or<'a, Q: PartialEq + ?Sized>(&'a self, k: &Q, default: &'a V) -> &'a V
+    where
+        K: Borrow<Q>,
+    {
+        self.get(k).unwrap_or(default)
+    }
+
+    /// Get a clone of a single value, or the default value if the key is absent.
+    #[inline]
+    #[must_use]
+    pub fn get_or_default<Q: PartialEq + ?Sized>(&self, k: &Q) -> V
+    where
+        K: Borrow<Q>,
+        V: Default + Clone,
+    {
+        self.get(k).cloned().unwrap_or_default()
+    }
+
     /// Get a mutable reference to a single value.
-    ///
-    /// # Panics
-    ///
-    /// If can't turn it into a mutable state.
     #[inline]
     #[must_use]
     pub fn get_mut<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> Option<&mut V>
     where
         K: Borrow<Q>,
     {
-        for i in 0..self.next {
-            if let Some(p1) = self.item(i) {
-                if p1.0.borrow() == k {
-                    let p2 = unsafe { self.pairs[i].assume_init_mut() };
-                    return Some(&mut p2.as_mut().unwrap().1);
-                }
-            }
-        }
-        None
+        self.pairs[..self.next]
+            .iter_mut()
+            .find_map(|p| match unsafe { p.assume_init_mut() } {
+                Some(p) if p.0.borrow() == k => Some(&mut p.1),
+                _ => None,
+            })
+    }
+
+    /// Get a reference to a single value, starting the search at the
+    /// given position of the internal array.
+    ///
+    /// The search wraps around, so the slots before `start` are visited
+    /// too, after all others. Thus, the key is always found, if it's present,
+    /// but faster if `start` is at or right before its position.
+    #[inline]
+    #[must_use]
+    pub fn get_from_index<Q: PartialEq + ?Sized>(&self, start: usize, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let i = self.index_from(start, k)?;
+        self.item(i).map(|p| &p.1)
+    }
+
+    /// Get a mutable reference to a single value, starting the search at the
+    /// given position of the internal array, like [`Map::get_from_index`] does.
+    #[inline]
+    #[must_use]
+    pub fn get_mut_from_index<Q: PartialEq + ?Sized>(
+        &mut self,
+        start: usize,
+        k: &Q,
+    ) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let i = self.index_from(start, k)?;
+        unsafe { self.pairs[i].assume_init_mut() }
+            .as_mut()
+            .map(|p| &mut p.1)
+    }
+
+    /// Internal function to find the position of the key in the internal
+    /// array, starting at the given position and wrapping around.
+    #[inline]
+    fn index_from<Q: PartialEq + ?Sized>(&self, start: usize, k: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        let start = start.min(self.next);
+        (start..self.next)
+            .chain(0..start)
+            .find(|&i| self.item(i).is_some_and(|p| p.0.borrow() == k))
     }
 
     /// Remove all pairs from it, but keep the space intact for future use.
     #[inline]
     pub fn clear(&mut self) {
+        self.clear_hint();
+    }
+
+    /// Remove all pairs from it, like [`Map::clear`] does, and return
+    /// the number of pairs removed.
+    #[inline]
+    pub fn clear_hint(&mut self) -> usize {
+        let mut busy = 0;
         for i in 0..self.next {
+            if self.item(i).is_some() {
+                busy += 1;
+            }
             unsafe { self.pairs[i].assume_init_drop() };
         }
         self.next = 0;
+        self.check_trimmed();
+        busy
+    }
+
+    /// Move all pairs to the front of the internal array, keeping their order,
+    /// so that there are no holes left by removals.
+    #[inline]
+    pub fn compact(&mut self) {
+        self.compact_by(|_, _| {});
+    }
+
+    /// Compact it, like [`Map::compact`] does, and return the old and
+    /// the new positions of every pair that was moved.
+    ///
+    /// The pairs that stayed where they were are not reported.
+    #[inline]
+    pub fn compact_with_remap(&mut self) -> impl Iterator<Item = (usize, usize)> {
+        let mut moves = [(0, 0); N];
+        let mut count = 0;
+        self.compact_by(|i, j| {
+            moves[count] = (i, j);
+            count += 1;
+        });
+        moves.into_iter().take(count)
+    }
+
+    /// Internal function to compact it, telling the old and the new
+    /// positions of every pair that was moved.
+    #[inline]
+    fn compact_by(&mut self, mut moved: impl FnMut(usize, usize)) {
+        let mut j = 0;
+        for i in 0..self.next {
+            if self.item(i).is_some() {
+                if i != j {
+                    self.pairs.swap(i, j);
+                    moved(i, j);
+                }
+                j += 1;
+            }
+        }
+        self.next = j;
+        self.check_trimmed();
+    }
+
+    /// Are all pairs at the front of the internal array, without holes?
+    #[inline]
+    #[must_use]
+    pub fn is_compact(&self) -> bool {
+        self.next == self.len()
+    }
+
+    /// Move all pairs into an array of exactly `M` pairs, in the order
+    /// of the internal array.
+    ///
+    /// # Errors
+    ///
+    /// If the map is not compact or the number of pairs is not `M`,
+    /// the map is returned back untouched.
+    #[inline]
+    #[allow(clippy::uninit_assumed_init)]
+    pub fn try_into_array<const M: usize>(mut self) -> Result<[(K, V); M], Self> {
+        if self.next != M || !self.is_compact() {
+            return Err(self);
+        }
+        let mut arr: [MaybeUninit<(K, V)>; M] =
+            unsafe { MaybeUninit::<[MaybeUninit<(K, V)>; M]>::uninit().assume_init() };
+        for (i, a) in arr.iter_mut().enumerate() {
+            if let Some(p) = unsafe { self.pairs[i].assume_init_read() } {
+                a.write(p);
+            }
+        }
+        self.next = 0;
+        Ok(unsafe { arr.as_ptr().cast::<[(K, V); M]>().read() })
+    }
+
+    /// Copy all pairs into the front of a new array, in the order of the
+    /// slots, returning it together with the number of pairs copied.
+    ///
+    /// The rest of the array is filled with default pairs.
+    #[inline]
+    #[must_use]
+    pub fn snapshot_copy(&self) -> ([(K, V); N], usize)
+    where
+        K: Copy + Default,
+        V: Copy + Default,
+    {
+        let mut arr = [(K::default(), V::default()); N];
+        let mut n = 0;
+        for (k, v) in self {
+            arr[n] = (*k, *v);
+            n += 1;
+        }
+        (arr, n)
+    }
+
+    /// Keep the first `len` pairs, in the order of the internal array,
+    /// and drop all others.
+    ///
+    /// If `len` is not smaller than the number of pairs, nothing happens.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        let mut kept = 0;
+        let mut i = 0;
+        while i < self.next && kept < len {
+            if self.item(i).is_some() {
+                kept += 1;
+            }
+            i += 1;
+        }
+        for j in i..self.next {
+            unsafe { self.pairs[j].assume_init_drop() };
+        }
+        self.next = i;
+        self.check_invariants();
     }
 
     /// Retains only the elements specified by the predicate.
     #[inline]
-    pub fn retain<F: Fn(&K, &V) -> bool>(&mut self, f: F) {
+    pub fn retain<F: FnMut(&K, &V) -> bool>(&mut self, f: F) {
+        self.retain_count(f);
+    }
+
+    /// Retains only the pairs with the keys specified by the predicate.
+    #[inline]
+    pub fn retain_keys<F: FnMut(&K) -> bool>(&mut self, mut f: F) {
+        self.retain(|k, _| f(k));
+    }
+
+    /// Retains only the pairs with the values specified by the predicate.
+    #[inline]
+    pub fn retain_values<F: FnMut(&V) -> bool>(&mut self, mut f: F) {
+        self.retain(|_, v| f(v));
+    }
+
+    /// Keep only the first pair for each distinct value, removing
+    /// all later pairs with the same values.
+    ///
+    /// Every value is compared with all values before it, so it's O(n²),
+    /// which is acceptable for small maps only.
+    #[inline]
+    pub fn retain_unique_values(&mut self)
+    where
+        V: PartialEq,
+    {
+        for i in 0..self.next {
+            let Some((_, v)) = self.item(i) else {
+                continue;
+            };
+            if (0..i).any(|j| self.item(j).is_some_and(|p| &p.1 == v)) {
+                unsafe { self.pairs[i].assume_init_drop() };
+                self.pairs[i].write(None);
+            }
+        }
+        self.check_invariants();
+    }
+
+    /// Retains only the elements specified by the predicate and
+    /// returns the number of pairs removed.
+    #[inline]
+    pub fn retain_count<F: FnMut(&K, &V) -> bool>(&mut self, mut f: F) -> usize {
+        let mut removed = 0;
         for i in 0..self.next {
             if let Some((k, v)) = self.item(i) {
                 if !f(k, v) {
+                    unsafe { self.pairs[i].assume_init_drop() };
+                    self.pairs[i].write(None);
+                    removed += 1;
+                }
+            }
+        }
+        self.check_invariants();
+        removed
+    }
+
+    /// Remove the pairs from the front, in the order of the slots, while
+    /// the predicate holds, and return the number of pairs removed.
+    ///
+    /// The first pair, which doesn't match the predicate, stops it.
+    #[inline]
+    pub fn remove_while<F: FnMut(&K, &V) -> bool>(&mut self, mut pred: F) -> usize {
+        let mut removed = 0;
+        for i in 0..self.next {
+            if let Some((k, v)) = self.item(i) {
+                if !pred(k, v) {
+                    break;
+                }
+                unsafe { self.pairs[i].assume_init_drop() };
+                self.pairs[i].write(None);
+                removed += 1;
+            }
+        }
+        self.check_invariants();
+        removed
+    }
+
+    /// Retains only the elements specified by the predicate, like
+    /// [`Map::retain`] does, but also moves the kept pairs to the front,
+    /// keeping their order, so that there are no holes left.
+    #[inline]
+    pub fn retain_compacting<F: FnMut(&K, &V) -> bool>(&mut self, mut f: F) {
+        let mut j = 0;
+        for i in 0..self.next {
+            if let Some((k, v)) = self.item(i) {
+                if f(k, v) {
+                    if i != j {
+                        self.pairs.swap(i, j);
+                    }
+                    j += 1;
+                } else {
+                    unsafe { self.pairs[i].assume_init_drop() };
                     self.pairs[i].write(None);
                 }
             }
         }
+        self.next = j;
+        self.check_trimmed();
+    }
+
+    /// Retains only the elements specified by the predicate, which also
+    /// gets the ordinal number of each pair: 0, 1, 2, and so on.
+    #[inline]
+    pub fn retain_indexed<F: FnMut(usize, &K, &V) -> bool>(&mut self, mut f: F) {
+        let mut ordinal = 0;
+        for i in 0..self.next {
+            if let Some((k, v)) = self.item(i) {
+                if !f(ordinal, k, v) {
+                    unsafe { self.pairs[i].assume_init_drop() };
+                    self.pairs[i].write(None);
+                }
+                ordinal += 1;
+            }
+        }
+        self.check_invariants();
+    }
+
+    /// Fold all pairs into a single value, passing keys and values
+    /// to the function separately.
+    #[inline]
+    pub fn fold<B, F: FnMut(B, &K, &V) -> B>(&self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        for (k, v) in self {
+            acc = f(acc, k, v);
+        }
+        acc
+    }
+
+    /// Swap the values of two keys.
+    ///
+    /// Returns `false` and does nothing, if any of the keys is absent.
+    #[inline]
+    pub fn swap_values<Q: PartialEq + ?Sized>(&mut self, a: &Q, b: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        let (Some(i), Some(j)) = (self.index_of(a), self.index_of(b)) else {
+            return false;
+        };
+        if i != j {
+            let (left, right) = self.pairs.split_at_mut(i.max(j));
+            let p1 = unsafe { left[i.min(j)].assume_init_mut() };
+            let p2 = unsafe { right[0].assume_init_mut() };
+            if let (Some(p1), Some(p2)) = (p1, p2) {
+                mem::swap(&mut p1.1, &mut p2.1);
+            }
+        }
+        true
+    }
+
+    /// Get mutable references to the values of two different keys at once.
+    ///
+    /// Returns `None` if any of the keys is absent, or if both keys
+    /// point to the same pair.
+    #[inline]
+    #[must_use]
+    pub fn get2_mut<Q: PartialEq + ?Sized>(&mut self, a: &Q, b: &Q) -> Option<(&mut V, &mut V)>
+    where
+        K: Borrow<Q>,
+    {
+        let (i, j) = (self.index_of(a)?, self.index_of(b)?);
+        if i == j {
+            return None;
+        }
+        let base = self.pairs.as_mut_ptr();
+        let p1 = unsafe { (*base.add(i)).assume_init_mut() };
+        let p2 = unsafe { (*base.add(j)).assume_init_mut() };
+        Some((&mut p1.as_mut()?.1, &mut p2.as_mut()?.1))
+    }
+
+    /// Get mutable references to the values at the given positions of
+    /// the internal array, all at once.
+    ///
+    /// Returns `None` if any of the positions is out of range, points to
+    /// an empty slot, or is the same as another one.
+    #[inline]
+    #[must_use]
+    pub fn get_disjoint_index_mut<const C: usize>(
+        &mut self,
+        indices: [usize; C],
+    ) -> Option<[&mut V; C]> {
+        for (n, &i) in indices.iter().enumerate() {
+            if i >= self.next || self.item(i).is_none() || indices[..n].contains(&i) {
+                return None;
+            }
+        }
+        let base = self.pairs.as_mut_ptr();
+        Some(indices.map(|i| {
+            let p = unsafe { (*base.add(i)).assume_init_mut() };
+            &mut unsafe { p.as_mut().unwrap_unchecked() }.1
+        }))
+    }
+
+    /// Get mutable references to the values of many different keys at once.
+    ///
+    /// # Errors
+    ///
+    /// If any of the keys is absent, [`GetManyError::Missing`] is returned
+    /// with its position among the keys. If two keys are the same,
+    /// [`GetManyError::Duplicate`] is returned with their positions.
+    #[inline]
+    pub fn try_get_many_mut<Q: PartialEq + ?Sized, const C: usize>(
+        &mut self,
+        keys: [&Q; C],
+    ) -> Result<[&mut V; C], GetManyError>
+    where
+        K: Borrow<Q>,
+    {
+        let mut indices = [0; C];
+        for (n, k) in keys.iter().enumerate() {
+            let i = self.index_of(*k).ok_or(GetManyError::Missing(n))?;
+            if let Some(m) = indices[..n].iter().position(|&j| j == i) {
+                return Err(GetManyError::Duplicate(m, n));
+            }
+            indices[n] = i;
+        }
+        let base = self.pairs.as_mut_ptr();
+        Ok(indices.map(|i| {
+            let p = unsafe { (*base.add(i)).assume_init_mut() };
+            &mut unsafe { p.as_mut().unwrap_unchecked() }.1
+        }))
+    }
+
+    /// Count the pairs preceding the key in the order of iteration.
+    ///
+    /// Returns `None` if the key is absent.
+    #[inline]
+    #[must_use]
+    pub fn rank<Q: PartialEq + ?Sized>(&self, k: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        let i = self.index_of(k)?;
+        Some((0..i).filter(|&j| self.item(j).is_some()).count())
+    }
+
+    /// Get the position of the key in the internal array, if it's there.
+    ///
+    /// The position stays the same until the pair is removed, or the map
+    /// is compacted.
+    #[inline]
+    #[must_use]
+    pub fn index_of<Q: PartialEq + ?Sized>(&self, k: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        (0..self.next).find(|&i| self.item(i).is_some_and(|p| p.0.borrow() == k))
+    }
+
+    /// Internal function to make sure the bookkeeping of the slots is sane:
+    /// the used slots fit into the internal array. The slots at and after
+    /// `next` are never initialized, so there is nothing to check in them.
+    ///
+    /// It checks only in the "debug" mode.
+    #[inline]
+    pub(crate) const fn check_invariants(&self) {
+        debug_assert!(self.next <= N, "Too many slots used in the map");
+    }
+
+    /// Internal function to make sure the bookkeeping of the slots is sane,
+    /// like [`Map::check_invariants`] does, and also that there are no empty
+    /// slots at the end of the used ones.
+    ///
+    /// It checks only in the "debug" mode.
+    #[inline]
+    pub(crate) const fn check_trimmed(&self) {
+        self.check_invariants();
+        debug_assert!(
+            self.next == 0 || self.item(self.next - 1).is_some(),
+            "Empty slots left at the end of the map"
+        );
+    }
+
+    /// Internal function to make sure the slot is inside the internal array.
+    ///
+    /// It checks only in the "debug" mode, unless the `checked-insert`
+    /// feature is enabled.
+    #[inline]
+    pub(crate) const fn check_slot(i: usize) {
+        #[cfg(feature = "checked-insert")]
+        assert!(i < N, "No more keys available in the map");
+        #[cfg(not(feature = "checked-insert"))]
+        debug_assert!(i < N, "No more keys available in the map");
     }
 
     /// Internal function to get access to the element in the internal array.
     #[inline]
-    const fn item(&self, i: usize) -> &Option<(K, V)> {
-        unsafe { self.pairs[i].assume_init_ref() }
+    pub(crate) const fn item(&self, i: usize) -> Option<&(K, V)> {
+        unsafe { self.pairs[i].assume_init_ref() }.as_ref()
     }
 
     /// Returns the key-value pair corresponding to the supplied key.
@@ -207,6 +904,55 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         None
     }
 
+    /// Get a reference to the value of the first key matching the predicate.
+    #[inline]
+    pub fn get_by<F: Fn(&K) -> bool>(&self, pred: F) -> Option<&V> {
+        self.iter().find(|(k, _)| pred(k)).map(|p| p.1)
+    }
+
+    /// Does the map contain a key matching the predicate?
+    #[inline]
+    pub fn contains_by<F: Fn(&K) -> bool>(&self, pred: F) -> bool {
+        self.keys().any(pred)
+    }
+
+    /// Count the pairs matching the predicate.
+    #[inline]
+    pub fn count_matching<F: FnMut(&K, &V) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().filter(|(k, v)| pred(k, v)).count()
+    }
+
+    /// Find the first pair matching the predicate.
+    #[inline]
+    pub fn find<F: FnMut(&K, &V) -> bool>(&self, mut pred: F) -> Option<(&K, &V)> {
+        self.iter().find(|(k, v)| pred(k, v))
+    }
+
+    /// Find the pair with the maximum value, according to the comparison function.
+    ///
+    /// If several pairs are equally maximum, the last one is returned.
+    #[inline]
+    pub fn max_by<F: FnMut(&V, &V) -> Ordering>(&self, mut f: F) -> Option<(&K, &V)> {
+        self.iter().max_by(|a, b| f(a.1, b.1))
+    }
+
+    /// Find the pair with the minimum value, according to the comparison function.
+    ///
+    /// If several pairs are equally minimum, the first one is returned.
+    #[inline]
+    pub fn min_by<F: FnMut(&V, &V) -> Ordering>(&self, mut f: F) -> Option<(&K, &V)> {
+        self.iter().min_by(|a, b| f(a.1, b.1))
+    }
+
+    /// Remove the first pair with the key matching the predicate, returning it.
+    #[inline]
+    pub fn remove_by<F: Fn(&K) -> bool>(&mut self, pred: F) -> Option<(K, V)> {
+        let i = (0..self.next).find(|&i| self.item(i).is_some_and(|p| pred(&p.0)))?;
+        let ret = mem::replace(&mut self.pairs[i], MaybeUninit::new(None));
+        self.check_invariants();
+        unsafe { ret.assume_init() }
+    }
+
     /// Removes a key from the map, returning the stored key and value if the
     /// key was previously in the map.
     #[inline]
@@ -218,6 +964,7 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
             if let Some(p) = self.item(i) {
                 if p.0.borrow() == k {
                     let ret = mem::replace(&mut self.pairs[i], MaybeUninit::new(None));
+                    self.check_invariants();
                     unsafe {
                         return ret.assume_init();
                     }
@@ -226,38 +973,151 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         }
         None
     }
+
+    /// Remove by key, returning the position of the freed slot
+    /// in the internal array together with the removed pair.
+    #[inline]
+    pub fn remove_full<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> Option<(usize, K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let i = self.index_of(k)?;
+        let (k, v) = unsafe { self.pairs[i].assume_init_mut() }.take()?;
+        self.check_invariants();
+        Some((i, k, v))
+    }
 }
 
 #[cfg(test)]
 mod test {
 
-    use super::*;
+    use super::*;
+
+    #[test]
+    fn insert_and_check_length() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("first".to_string(), 42);
+        assert_eq!(1, m.len());
+        m.insert("second".to_string(), 16);
+        assert_eq!(2, m.len());
+        m.insert("first".to_string(), 16);
+        assert_eq!(2, m.len());
+    }
+
+    #[test]
+    fn overwrites_keys() {
+        let mut m: Map<i32, i32, 1> = Map::new();
+        m.insert(1, 42);
+        m.insert(1, 42);
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(clippy::should_panic_without_expect)]
+    fn cant_write_into_empty_map() {
+        let mut m: Map<i32, i32, 0> = Map::new();
+        m.insert(1, 42);
+    }
+
+    #[test]
+    fn extends_from_slice() {
+        let mut m: Map<u8, u8, 4> = Map::new();
+        m.insert(1, 1);
+        m.extend_from_slice(&[(1, 10), (2, 20), (3, 30)]);
+        assert_eq!(3, m.len());
+        assert_eq!(10, m[&1]);
+        assert_eq!(30, m[&3]);
+    }
+
+    #[test]
+    fn merges_with_resolver() {
+        let mut a: Map<&str, i32, 4> = Map::new();
+        a.insert("x", 1);
+        a.insert("y", 2);
+        let mut b: Map<&str, i32, 2> = Map::new();
+        b.insert("y", 10);
+        b.insert("z", 20);
+        a.merge_with(b, |_, a, b| *a += b);
+        assert_eq!(3, a.len());
+        assert_eq!(1, a["x"]);
+        assert_eq!(12, a["y"]);
+        assert_eq!(20, a["z"]);
+    }
+
+    #[test]
+    fn merges_into_free_slots() {
+        let mut a: Map<&str, i32, 3> = Map::new();
+        a.insert("x", 1);
+        a.insert("y", 2);
+        a.insert("z", 3);
+        a.remove("x");
+        let mut b: Map<&str, i32, 2> = Map::new();
+        b.insert("z", 30);
+        b.insert("w", 40);
+        a.merge_with(b, |_, a, b| *a = b);
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            [(&"w", &40), (&"y", &2), (&"z", &30)]
+        );
+    }
+
+    #[test]
+    fn partitions_into_two_maps() {
+        let mut m: Map<i32, i32, 6> = Map::new();
+        for i in 0..6 {
+            m.insert(i, i * 10);
+        }
+        let (even, odd): (Map<i32, i32, 3>, Map<i32, i32, 3>) = m.partition(|k, _| k % 2 == 0);
+        assert_eq!(even.keys().collect::<Vec<_>>(), [&0, &2, &4]);
+        assert_eq!(odd.keys().collect::<Vec<_>>(), [&1, &3, &5]);
+        assert_eq!(30, odd[&3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "No more keys available in the map")]
+    #[cfg(feature = "checked-insert")]
+    fn checks_overflow_in_any_mode() {
+        let mut m: Map<i32, i32, 2> = Map::new();
+        for i in 0..3 {
+            m.insert(i, i);
+        }
+    }
 
     #[test]
-    fn insert_and_check_length() {
-        let mut m: Map<String, i32, 10> = Map::new();
-        m.insert("first".to_string(), 42);
-        assert_eq!(1, m.len());
-        m.insert("second".to_string(), 16);
-        assert_eq!(2, m.len());
-        m.insert("first".to_string(), 16);
-        assert_eq!(2, m.len());
+    fn is_send_and_sync() {
+        const fn assert_send<T: Send>() {}
+        const fn assert_sync<T: Sync>() {}
+        assert_send::<Map<String, Vec<u8>, 8>>();
+        assert_sync::<Map<String, Vec<u8>, 8>>();
+        assert_send::<Map<u8, std::sync::Arc<u8>, 0>>();
+        assert_sync::<Map<u8, std::sync::Arc<u8>, 0>>();
     }
 
     #[test]
-    fn overwrites_keys() {
-        let mut m: Map<i32, i32, 1> = Map::new();
-        m.insert(1, 42);
-        m.insert(1, 42);
+    fn inserts_checked() {
+        let mut m: Map<i32, i32, 2> = Map::new();
+        assert!(m.insert_checked(1, 1));
+        assert!(!m.insert_checked(1, 2));
+        assert_eq!(2, m[&1]);
+        m.remove(&1);
+        assert!(m.insert_checked(1, 3));
         assert_eq!(1, m.len());
     }
 
     #[test]
-    #[should_panic]
-    #[cfg(debug_assertions)]
-    fn cant_write_into_empty_map() {
-        let mut m: Map<i32, i32, 0> = Map::new();
-        m.insert(1, 42);
+    fn counts_max_additional() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        assert_eq!(4, m.max_additional());
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.insert(3, 3);
+        assert_eq!(1, m.max_additional());
+        m.remove(&2);
+        assert_eq!(1, m.max_additional());
+        m.insert(4, 4);
+        m.insert(5, 5);
+        assert_eq!(0, m.max_additional());
     }
 
     #[test]
@@ -266,6 +1126,17 @@ mod test {
         assert_eq!(0, m.len());
     }
 
+    #[test]
+    fn calculates_load() {
+        let mut m: Map<u32, u32, 4> = Map::new();
+        assert!(m.load().abs() < f32::EPSILON);
+        m.insert(1, 1);
+        m.insert(2, 2);
+        assert!((m.load() - 0.5).abs() < f32::EPSILON);
+        let z: Map<u32, u32, 0> = Map::new();
+        assert!(z.load().abs() < f32::EPSILON);
+    }
+
     #[test]
     fn is_empty_check() {
         let mut m: Map<u32, u32, 10> = Map::new();
@@ -282,6 +1153,40 @@ mod test {
         assert_eq!(16, *m.get("two").unwrap());
     }
 
+    #[test]
+    fn gets_or_fallback() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("one", 1);
+        assert_eq!(&1, m.get_or("one", &42));
+        assert_eq!(&42, m.get_or("two", &42));
+    }
+
+    #[test]
+    fn gets_or_default() {
+        let mut m: Map<&str, String, 4> = Map::new();
+        m.insert("one", "uno".to_string());
+        assert_eq!("uno", m.get_or_default("one"));
+        assert_eq!("", m.get_or_default("two"));
+    }
+
+    #[test]
+    fn replaces_present_key() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("one", 1);
+        assert_eq!(Ok(1), m.replace("one", 11));
+        assert_eq!(11, m["one"]);
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn does_not_replace_absent_key() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("one", 1);
+        assert_eq!(Err(("two", 2)), m.replace("two", 2));
+        assert!(!m.contains_key("two"));
+        assert_eq!(1, m.len());
+    }
+
     #[test]
     fn insert_and_gets_mut() {
         let mut m: Map<i32, [i32; 3], 10> = Map::new();
@@ -352,7 +1257,7 @@ mod test {
 
     #[test]
     fn large_map_in_heap() {
-        let m: Box<Map<u64, [u64; 10], 10>> = Box::new(Map::new());
+        let m: Box<Map<u64, [u64; 10], 10>> = Box::default();
         assert_eq!(0, m.len());
     }
 
@@ -364,6 +1269,23 @@ mod test {
         assert_eq!(0, m.len());
     }
 
+    #[test]
+    fn clears_with_hint() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, Rc::clone(&v));
+        }
+        m.remove(&1);
+        assert_eq!(4, m.clear_hint());
+        assert_eq!(1, Rc::strong_count(&v));
+        assert!(m.is_empty());
+        assert_eq!(0, m.clear_hint());
+        m.insert(1, Rc::clone(&v));
+        assert_eq!(1, m.len());
+    }
+
     #[test]
     fn retain_test() {
         let vec: Vec<(i32, i32)> = (0..8).map(|x| (x, x * 10)).collect();
@@ -375,6 +1297,129 @@ mod test {
         assert_eq!(m.len(), 2);
     }
 
+    #[test]
+    fn compacts_holes() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i);
+        }
+        assert!(m.is_compact());
+        m.remove(&1);
+        m.remove(&3);
+        assert!(!m.is_compact());
+        m.compact();
+        assert!(m.is_compact());
+        assert_eq!(3, m.next);
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&0, &2, &4]);
+        m.insert(5, 5);
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&0, &2, &4, &5]);
+    }
+
+    #[test]
+    fn compacts_trailing_holes() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.remove(&2);
+        m.compact();
+        assert_eq!(1, m.next);
+        m.remove(&1);
+        m.compact();
+        assert_eq!(0, m.next);
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn truncates_to_earliest() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, Rc::clone(&v));
+        }
+        m.truncate(2);
+        assert_eq!(2, m.len());
+        assert_eq!(3, Rc::strong_count(&v));
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&0, &1]);
+        m.truncate(10);
+        assert_eq!(2, m.len());
+        m.truncate(0);
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn retain_count_test() {
+        let mut m: Map<i32, i32, 10> = (0..8).map(|x| (x, x)).collect();
+        let before = m.len();
+        let removed = m.retain_count(|&k, _| k % 3 == 0);
+        assert_eq!(5, removed);
+        assert_eq!(before - removed, m.len());
+        assert_eq!(0, m.retain_count(|_, _| true));
+    }
+
+    #[test]
+    fn retains_by_ordinal() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, Rc::clone(&v));
+        }
+        m.remove(&1);
+        m.retain_indexed(|i, _, _| i % 2 == 0);
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&0, &3, &5]);
+        assert_eq!(4, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn retains_keys() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, Rc::clone(&v));
+        }
+        m.retain_keys(|k| k % 2 == 1);
+        assert_eq!(3, m.len());
+        assert_eq!(4, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn retains_values() {
+        use std::rc::Rc;
+        let mut m: Map<i32, Rc<i32>, 8> = Map::new();
+        let values: Vec<Rc<i32>> = (0..6).map(Rc::new).collect();
+        for (i, v) in values.iter().enumerate() {
+            m.insert(i32::try_from(i).unwrap(), Rc::clone(v));
+        }
+        m.retain_values(|v| **v >= 4);
+        assert_eq!(2, m.len());
+        assert_eq!(1, Rc::strong_count(&values[0]));
+        assert_eq!(2, Rc::strong_count(&values[5]));
+    }
+
+    #[test]
+    fn retain_drops_removed() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 4> = Map::new();
+        for i in 0..4 {
+            m.insert(i, Rc::clone(&v));
+        }
+        m.retain(|&k, _| k < 1);
+        assert_eq!(2, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn folds_pairs() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        m.insert(1, 10);
+        m.insert(2, 20);
+        m.insert(3, 30);
+        m.remove(&2);
+        assert_eq!(44, m.fold(0, |acc, k, v| acc + k + v));
+    }
+
     #[test]
     fn insert_many_and_remove() {
         let mut m: Map<usize, u64, 4> = Map::new();
@@ -403,6 +1448,52 @@ mod test {
         assert_eq!(m.get_key_value("two"), None);
     }
 
+    #[test]
+    fn gets_by_predicate() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("One".to_string(), 1);
+        m.insert("TWO".to_string(), 2);
+        assert_eq!(Some(&2), m.get_by(|k| k.eq_ignore_ascii_case("two")));
+        assert_eq!(None, m.get_by(|k| k.eq_ignore_ascii_case("three")));
+        assert!(m.contains_by(|k| k.eq_ignore_ascii_case("one")));
+        assert!(!m.contains_by(String::is_empty));
+    }
+
+    #[test]
+    fn finds_first_matching_pair() {
+        let mut m: Map<&str, i32, 10> = Map::new();
+        m.insert("a", 5);
+        m.insert("b", 50);
+        m.insert("c", 500);
+        assert_eq!(Some((&"b", &50)), m.find(|_, &v| v > 10));
+        assert_eq!(None, m.find(|_, &v| v > 1000));
+    }
+
+    #[test]
+    fn finds_max_and_min_values() {
+        let mut m: Map<&str, i32, 10> = Map::new();
+        assert_eq!(None, m.max_by(Ord::cmp));
+        m.insert("alice", 7);
+        m.insert("bob", 12);
+        m.insert("carol", 3);
+        assert_eq!(Some((&"bob", &12)), m.max_by(Ord::cmp));
+        assert_eq!(Some((&"carol", &3)), m.min_by(Ord::cmp));
+        assert_eq!(Some((&"carol", &3)), m.max_by(|a, b| b.cmp(a)));
+    }
+
+    #[test]
+    fn removes_by_predicate() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("One".to_string(), 1);
+        m.insert("TWO".to_string(), 2);
+        assert_eq!(
+            Some(("One".to_string(), 1)),
+            m.remove_by(|k| k.eq_ignore_ascii_case("one"))
+        );
+        assert_eq!(None, m.remove_by(|k| k.eq_ignore_ascii_case("one")));
+        assert_eq!(1, m.len());
+    }
+
     #[test]
     fn remove_entry_present() {
         let mut m: Map<String, i32, 10> = Map::new();
@@ -419,6 +1510,73 @@ mod test {
         assert_eq!(m.remove_entry("two"), None);
     }
 
+    #[test]
+    fn removes_all_keys() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i);
+        }
+        assert_eq!(3, m.remove_all([1, 3, 7, 4, 3]));
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&0, &2]);
+        assert_eq!(0, m.remove_all(Vec::new()));
+    }
+
+    #[test]
+    fn swaps_values() {
+        let mut m: Map<&str, i32, 8> = Map::new();
+        m.insert("a", 1);
+        m.insert("b", 2);
+        m.insert("c", 3);
+        assert!(m.swap_values("c", "a"));
+        assert_eq!(3, m["a"]);
+        assert_eq!(1, m["c"]);
+        assert!(m.swap_values("b", "b"));
+        assert_eq!(2, m["b"]);
+    }
+
+    #[test]
+    fn swaps_values_of_absent_key() {
+        let mut m: Map<&str, i32, 8> = Map::new();
+        m.insert("a", 1);
+        m.insert("b", 2);
+        assert!(!m.swap_values("a", "z"));
+        assert!(!m.swap_values("z", "b"));
+        assert_eq!(1, m["a"]);
+        assert_eq!(2, m["b"]);
+    }
+
+    #[test]
+    fn removes_by_index() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        m.insert(1, 10);
+        m.insert(2, 20);
+        m.insert(3, 30);
+        assert_eq!(Some((2, 20)), m.remove_index(1));
+        assert_eq!(3, m.next);
+        assert_eq!(Some((3, 30)), m.remove_index(2));
+        assert_eq!(1, m.next);
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn removes_by_index_of_hole() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        m.insert(1, 10);
+        m.insert(2, 20);
+        m.remove(&1);
+        assert_eq!(None, m.remove_index(0));
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn removes_by_index_out_of_range() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        m.insert(1, 10);
+        assert_eq!(None, m.remove_index(1));
+        assert_eq!(None, m.remove_index(10));
+        assert_eq!(1, m.len());
+    }
+
     #[test]
     fn drop_removed_entry() {
         use std::rc::Rc;
@@ -459,4 +1617,389 @@ mod test {
         assert_eq!(1, m.len());
         assert_eq!(3, m[&2]);
     }
+
+    #[test]
+    fn gets_two_values_mutably() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("a", 1);
+        m.insert("b", 2);
+        let (a, b) = m.get2_mut("a", "b").unwrap();
+        mem::swap(a, b);
+        *a += 10;
+        assert_eq!(12, m["a"]);
+        assert_eq!(1, m["b"]);
+    }
+
+    #[test]
+    fn refuses_two_values_of_absent_key() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("a", 1);
+        assert!(m.get2_mut("a", "x").is_none());
+        assert!(m.get2_mut("x", "a").is_none());
+    }
+
+    #[test]
+    fn refuses_two_values_of_same_key() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("a", 1);
+        m.insert("b", 2);
+        assert!(m.get2_mut("b", "b").is_none());
+    }
+
+    #[test]
+    fn moves_into_array() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 4> = Map::new();
+        m.insert(1, Rc::clone(&v));
+        m.insert(2, Rc::clone(&v));
+        let arr: [(i32, Rc<()>); 2] = m.try_into_array().ok().unwrap();
+        assert_eq!([1, 2], arr.each_ref().map(|p| p.0));
+        assert_eq!(3, Rc::strong_count(&v));
+        drop(arr);
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn refuses_to_move_into_wrong_array() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.insert(3, 3);
+        let m = m.try_into_array::<2>().unwrap_err();
+        let mut m = m.try_into_array::<4>().unwrap_err();
+        m.remove(&1);
+        let m = m.try_into_array::<2>().unwrap_err();
+        assert_eq!(2, m.len());
+        assert_eq!(3, m[&3]);
+    }
+
+    #[test]
+    fn ranks_keys_in_iteration_order() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("a", 1);
+        m.insert("b", 2);
+        m.insert("c", 3);
+        assert_eq!(Some(2), m.rank("c"));
+        m.remove("a");
+        assert_eq!(Some(1), m.rank("c"));
+        assert_eq!(Some(0), m.rank("b"));
+        assert_eq!(None, m.rank("a"));
+    }
+
+    #[test]
+    fn checks_many_keys() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("a", 1);
+        m.insert("b", 2);
+        assert!(m.contains_all(["a", "b"]));
+        assert!(!m.contains_all(["a", "x"]));
+        assert!(m.contains_any(["x", "b"]));
+        assert!(!m.contains_any(["x", "y"]));
+        assert!(m.contains_all::<&str, _>([]));
+        assert!(!m.contains_any::<&str, _>([]));
+    }
+
+    #[test]
+    fn retains_unique_values() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, (i32, Rc<()>), 4> = Map::new();
+        m.insert(1, (10, Rc::clone(&v)));
+        m.insert(2, (20, Rc::clone(&v)));
+        m.insert(3, (10, Rc::clone(&v)));
+        m.retain_unique_values();
+        assert_eq!(2, m.len());
+        assert!(m.contains_key(&1));
+        assert!(!m.contains_key(&3));
+        assert_eq!(3, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn bulk_loads_unique_keys() {
+        let mut m: Map<usize, usize, 64> = Map::new();
+        for i in 0..64 {
+            unsafe { m.insert_unique_unchecked(i, i * 2) };
+        }
+        assert_eq!(64, m.len());
+        assert_eq!(84, m[&42]);
+    }
+
+    #[test]
+    fn compacts_with_remap() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("a", 1);
+        m.insert("b", 2);
+        m.insert("c", 3);
+        m.insert("d", 4);
+        m.remove("b");
+        let remap: Vec<_> = m.compact_with_remap().collect();
+        assert_eq!(vec![(2, 1), (3, 2)], remap);
+        assert!(m.is_compact());
+        assert_eq!(Some(1), m.rank("c"));
+        assert_eq!(0, m.compact_with_remap().count());
+    }
+
+    #[test]
+    fn removes_leading_pairs() {
+        let mut m: Map<i32, &str, 8> = Map::new();
+        m.insert(1, "stale");
+        m.insert(2, "stale");
+        m.insert(3, "fresh");
+        m.insert(4, "stale");
+        m.remove(&2);
+        assert_eq!(1, m.remove_while(|_, v| *v == "stale"));
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&3, &4]);
+        assert_eq!(0, m.remove_while(|_, v| *v == "stale"));
+    }
+
+    #[test]
+    fn retains_and_compacts() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, Rc::clone(&v));
+        }
+        m.remove(&1);
+        m.retain_compacting(|k, _| k % 3 != 0);
+        assert!(m.is_compact());
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&2, &4, &5]);
+        assert_eq!(4, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn gets_from_index() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i * 10);
+        }
+        assert_eq!(Some(&30), m.get_from_index(1, &3));
+        assert_eq!(Some(&30), m.get_from_index(4, &3));
+        assert_eq!(Some(&30), m.get_from_index(42, &3));
+        assert_eq!(None, m.get_from_index(2, &7));
+        *m.get_mut_from_index(4, &1).unwrap() += 1;
+        assert_eq!(11, m[&1]);
+    }
+
+    #[test]
+    fn swap_removes_keeping_dense() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, i * 10);
+        }
+        assert_eq!(Some(10), m.swap_remove(&1));
+        assert_eq!(Some(0), m.swap_remove(&0));
+        assert_eq!(Some(50), m.swap_remove(&5));
+        assert_eq!(None, m.swap_remove(&5));
+        assert!(m.is_compact());
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&4, &3, &2]);
+    }
+
+    #[test]
+    fn swap_removes_keeping_earlier_holes() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i * 10);
+        }
+        m.remove(&1);
+        assert_eq!(Some(30), m.swap_remove(&3));
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&0, &2, &4]);
+        assert!(!m.is_compact());
+        assert_eq!(Some(40), m.swap_remove(&4));
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&0, &2]);
+        assert!(!m.is_compact());
+        m.compact();
+        assert!(m.is_compact());
+    }
+
+    #[test]
+    fn snapshots_pairs() {
+        let mut m: Map<u8, u16, 4> = Map::new();
+        m.insert(1, 10);
+        m.insert(2, 20);
+        m.insert(3, 30);
+        m.remove(&2);
+        m.insert(4, 40);
+        let (arr, n) = m.snapshot_copy();
+        assert_eq!(3, n);
+        assert_eq!([(1, 10), (4, 40), (3, 30)], arr[..n]);
+        assert_eq!((0, 0), arr[3]);
+    }
+
+    #[test]
+    fn removes_with_index() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("a", 1);
+        m.insert("b", 2);
+        m.insert("c", 3);
+        let i = m.index_of("b");
+        assert_eq!(Some(1), i);
+        assert_eq!(Some((1, "b", 2)), m.remove_full("b"));
+        assert_eq!(None, m.index_of("b"));
+        assert_eq!(None, m.remove_full("b"));
+        assert_eq!(2, m.len());
+    }
+
+    #[test]
+    fn counts_matching_pairs() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, i * 10);
+        }
+        m.remove(&5);
+        assert_eq!(2, m.count_matching(|_, v| *v > 20));
+        assert_eq!(0, m.count_matching(|k, _| *k > 42));
+    }
+
+    #[test]
+    fn gets_values_by_indices() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..4 {
+            m.insert(i, i * 10);
+        }
+        let [a, b] = m.get_disjoint_index_mut([3, 0]).unwrap();
+        *a += 1;
+        *b += 2;
+        assert_eq!(31, m[&3]);
+        assert_eq!(2, m[&0]);
+        m.remove(&1);
+        assert!(m.get_disjoint_index_mut([0, 1]).is_none());
+        assert!(m.get_disjoint_index_mut([2, 2]).is_none());
+        assert!(m.get_disjoint_index_mut([2, 7]).is_none());
+        assert!(m.get_disjoint_index_mut::<0>([]).is_some());
+    }
+
+    #[test]
+    fn reuses_slots_freed_by_retain() {
+        let mut m: Map<i32, i32, 6> = Map::new();
+        for i in 0..6 {
+            m.insert(i, i);
+        }
+        m.retain(|k, _| *k != 1 && *k != 3);
+        assert_eq!(4, m.len());
+        m.insert(10, 10);
+        assert_eq!(Some(1), m.index_of(&10));
+        m.insert(4, 44);
+        assert_eq!(Some(4), m.index_of(&4));
+        m.insert(11, 11);
+        assert_eq!(Some(3), m.index_of(&11));
+        assert_eq!(6, m.len());
+        assert_eq!(44, m[&4]);
+    }
+
+    #[test]
+    fn points_to_next_free_slot() {
+        let mut m: Map<i32, i32, 3> = Map::new();
+        assert_eq!(Some(0), m.next_free_index());
+        m.insert(1, 1);
+        m.insert(2, 2);
+        assert_eq!(Some(2), m.next_free_index());
+        m.insert(3, 3);
+        assert_eq!(None, m.next_free_index());
+        m.remove(&2);
+        assert_eq!(Some(1), m.next_free_index());
+        m.insert(4, 4);
+        assert_eq!(Some(1), m.index_of(&4));
+    }
+
+    #[test]
+    fn gets_many_values_or_explains_why_not() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("a", 1);
+        m.insert("b", 2);
+        m.insert("c", 3);
+        let [c, a] = m.try_get_many_mut(["c", "a"]).unwrap();
+        *c += 10;
+        *a += 20;
+        assert_eq!(13, m["c"]);
+        assert_eq!(21, m["a"]);
+        assert_eq!(
+            Some(GetManyError::Missing(1)),
+            m.try_get_many_mut(["a", "x", "b"]).err()
+        );
+        assert_eq!(
+            Some(GetManyError::Duplicate(0, 2)),
+            m.try_get_many_mut(["b", "a", "b"]).err()
+        );
+    }
+
+    #[test]
+    fn behaves_like_hash_map() {
+        use std::collections::HashMap;
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut rnd = move |n: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % n
+        };
+        let mut m: Map<u64, u64, 8> = Map::new();
+        let mut h: HashMap<u64, u64> = HashMap::new();
+        for step in 0..10_000 {
+            let k = rnd(12);
+            match rnd(8) {
+                0..=2 => {
+                    if h.contains_key(&k) || h.len() < 8 {
+                        m.insert(k, step);
+                        h.insert(k, step);
+                    }
+                }
+                3 | 4 => {
+                    m.remove(&k);
+                    h.remove(&k);
+                }
+                5 => {
+                    assert_eq!(h.remove(&k), m.swap_remove(&k));
+                }
+                6 => {
+                    let t = rnd(12);
+                    m.retain(|k, _| *k != t);
+                    h.retain(|k, _| *k != t);
+                }
+                _ => {
+                    if rnd(10) == 0 {
+                        m.clear();
+                        h.clear();
+                    } else {
+                        m.compact();
+                    }
+                }
+            }
+            assert_eq!(h.len(), m.len());
+            assert_eq!(h.get(&k), m.get(&k));
+            let keys: Vec<_> = m.keys().collect();
+            assert!(keys.iter().enumerate().all(|(i, k)| !keys[..i].contains(k)));
+            assert!(h.iter().all(|(k, v)| m.get(k) == Some(v)));
+        }
+    }
+
+    #[test]
+    fn retains_with_stateful_predicate() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i);
+        }
+        let mut calls = 0;
+        m.retain(|_, _| {
+            calls += 1;
+            calls <= 2
+        });
+        assert_eq!(5, calls);
+        assert_eq!(m.keys().collect::<Vec<_>>(), [&0, &1]);
+    }
+
+    #[test]
+    fn gets_mutable_values_of_present_keys() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        for i in 0..4 {
+            m.insert(i, i);
+        }
+        m.remove(&1);
+        for i in [0, 2, 3] {
+            *m.get_mut(&i).unwrap() += 10;
+        }
+        assert!(m.get_mut(&1).is_none());
+        assert_eq!(m.values().copied().collect::<Vec<_>>(), [10, 12, 13]);
+    }
 }
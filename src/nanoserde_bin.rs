@@ -0,0 +1,85 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+use alloc::vec::Vec;
+use nanoserde::{DeBin, DeBinErr, SerBin};
+
+impl<K: PartialEq + SerBin, V: SerBin, const N: usize> SerBin for Map<K, V, N> {
+    fn ser_bin(&self, output: &mut Vec<u8>) {
+        self.len().ser_bin(output);
+        for (k, v) in self {
+            k.ser_bin(output);
+            v.ser_bin(output);
+        }
+    }
+}
+
+impl<K: PartialEq + DeBin, V: DeBin, const N: usize> DeBin for Map<K, V, N> {
+    fn de_bin(offset: &mut usize, bytes: &[u8]) -> Result<Self, DeBinErr> {
+        let len: usize = DeBin::de_bin(offset, bytes)?;
+        if len > N {
+            return Err(DeBinErr::new(*offset, len, N));
+        }
+        let mut m = Self::new();
+        for _ in 0..len {
+            let k = DeBin::de_bin(offset, bytes)?;
+            let v = DeBin::de_bin(offset, bytes)?;
+            m.insert(k, v);
+        }
+        Ok(m)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn serialize_and_deserialize() {
+        let mut before: Map<u8, u32, 8> = Map::new();
+        before.insert(1, 42);
+        before.insert(2, 16);
+        let bytes = before.serialize_bin();
+        let after: Map<u8, u32, 8> = Map::deserialize_bin(&bytes).unwrap();
+        assert_eq!(2, after.len());
+        assert_eq!(42, after[&1]);
+        assert_eq!(16, after[&2]);
+    }
+
+    #[test]
+    fn rejects_too_many_pairs() {
+        let mut before: Map<u8, u32, 8> = Map::new();
+        for i in 0..5 {
+            before.insert(i, 0);
+        }
+        let bytes = before.serialize_bin();
+        assert!(<Map<u8, u32, 4>>::deserialize_bin(&bytes).is_err());
+    }
+
+    #[test]
+    fn reads_btree_map_format() {
+        let mut h = std::collections::BTreeMap::new();
+        h.insert(7_u8, 77_u32);
+        let m: Map<u8, u32, 2> = Map::deserialize_bin(&h.serialize_bin()).unwrap();
+        assert_eq!(77, m[&7]);
+    }
+}
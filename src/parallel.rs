@@ -0,0 +1,67 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+use core::mem::MaybeUninit;
+use rayon::iter::{FilterMap, IntoParallelIterator, ParallelIterator};
+use rayon::prelude::IntoParallelRefIterator;
+use rayon::slice::Iter;
+
+/// Internal function to get the pair out of the slot, if it is not empty.
+fn pair<K, V>(p: &MaybeUninit<Option<(K, V)>>) -> Option<(&K, &V)> {
+    unsafe { p.assume_init_ref() }
+        .as_ref()
+        .map(|p| (&p.0, &p.1))
+}
+
+impl<'a, K: PartialEq + Sync, V: Sync, const N: usize> IntoParallelIterator for &'a Map<K, V, N> {
+    type Item = (&'a K, &'a V);
+    #[allow(clippy::type_complexity)]
+    type Iter = FilterMap<
+        Iter<'a, MaybeUninit<Option<(K, V)>>>,
+        fn(&'a MaybeUninit<Option<(K, V)>>) -> Option<(&'a K, &'a V)>,
+    >;
+
+    /// Make a parallel iterator over all pairs.
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.pairs[..self.next].par_iter().filter_map(pair)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn sums_in_parallel() {
+        let mut m: Map<u32, u64, 64> = Map::new();
+        for i in 0..64 {
+            m.insert(i, u64::from(i) * 3);
+        }
+        m.remove(&7);
+        m.remove(&63);
+        let serial: u64 = m.values().sum();
+        let parallel: u64 = m.par_iter().map(|(_, v)| *v).sum();
+        assert_eq!(serial, parallel);
+        assert_eq!(62, m.par_iter().count());
+    }
+}
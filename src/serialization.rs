@@ -31,7 +31,7 @@ impl<K: PartialEq + Serialize, V: Serialize, const N: usize> Serialize for Map<K
         S: Serializer,
     {
         let mut map = serializer.serialize_map(Some(self.len()))?;
-        for (a, v) in self.iter() {
+        for (a, v) in self {
             map.serialize_entry(a, v)?;
         }
         map.end()
@@ -0,0 +1,313 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Map, Set, SetIter};
+use core::borrow::Borrow;
+use core::fmt;
+use core::fmt::{Debug, Formatter};
+use core::ops::{BitAnd, BitOr};
+
+impl<T: PartialEq, const N: usize> Default for Set<T, N> {
+    /// Make a default empty [`Set`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq + Clone, const N: usize> Clone for Set<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq + Debug, const N: usize> Debug for Set<T, N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<Set<T, M>> for Set<T, N> {
+    /// Two sets are equal if they contain the same values,
+    /// regardless of their order and capacities.
+    #[inline]
+    fn eq(&self, other: &Set<T, M>) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for Set<T, N> {}
+
+impl<T: PartialEq, const N: usize> FromIterator<T> for Set<T, N> {
+    /// Make a set from the values of the iterator, ignoring duplicates.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are too many values for the capacity of the
+    /// set, exactly like [`Set::insert`] does.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut s = Self::new();
+        for k in iter {
+            s.insert(k);
+        }
+        s
+    }
+}
+
+impl<T: PartialEq, const N: usize> Set<T, N> {
+    /// Make it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { map: Map::new() }
+    }
+
+    /// Get its total capacity.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Is it empty?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Return the total number of values inside.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Does the set contain this value?
+    #[inline]
+    #[must_use]
+    pub fn contains<Q: PartialEq + ?Sized>(&self, k: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.contains_key(k)
+    }
+
+    /// Insert a single value into the set.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are too many values in the set already, exactly
+    /// like [`Map::insert`] does.
+    #[inline]
+    pub fn insert(&mut self, k: T) {
+        self.map.insert(k, ());
+    }
+
+    /// Remove a value.
+    #[inline]
+    pub fn remove<Q: PartialEq + ?Sized>(&mut self, k: &Q)
+    where
+        T: Borrow<Q>,
+    {
+        self.map.remove(k);
+    }
+
+    /// Remove all values from it, but keep the space intact for future use.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Make an iterator over all values.
+    #[inline]
+    #[must_use]
+    pub const fn iter(&self) -> SetIter<'_, T, N> {
+        SetIter {
+            iter: self.map.keys(),
+        }
+    }
+
+    /// Make an iterator over the values present in both sets.
+    ///
+    /// Every value of this set is looked up in the other one, which makes
+    /// it O(n·m), since both sets are scanned linearly. This is fine
+    /// for small sets.
+    #[inline]
+    pub fn intersection<'a, const M: usize>(
+        &'a self,
+        other: &'a Set<T, M>,
+    ) -> impl Iterator<Item = &'a T> {
+        self.iter().filter(move |k| other.contains(*k))
+    }
+
+    /// Make an iterator over the values present in this set, but not in the other one.
+    ///
+    /// Every value of this set is looked up in the other one, which makes
+    /// it O(n·m), since both sets are scanned linearly. This is fine
+    /// for small sets.
+    #[inline]
+    pub fn difference<'a, const M: usize>(
+        &'a self,
+        other: &'a Set<T, M>,
+    ) -> impl Iterator<Item = &'a T> {
+        self.iter().filter(move |k| !other.contains(*k))
+    }
+}
+
+impl<'a, T: PartialEq, const N: usize> Iterator for SetIter<'a, T, N> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<'a, T: PartialEq, const N: usize> IntoIterator for &'a Set<T, N> {
+    type Item = &'a T;
+    type IntoIter = SetIter<'a, T, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq + Clone, const N: usize> BitOr<&Set<T, N>> for &Set<T, N> {
+    type Output = Set<T, N>;
+
+    /// Make a new set with all values from both sets.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are too many values for the capacity of the
+    /// new set, exactly like [`Set::insert`] does.
+    #[inline]
+    fn bitor(self, rhs: &Set<T, N>) -> Set<T, N> {
+        let mut s = Set::new();
+        for k in self.iter().chain(rhs.difference(self)) {
+            s.insert(k.clone());
+        }
+        s
+    }
+}
+
+impl<T: PartialEq + Clone, const N: usize> BitAnd<&Set<T, N>> for &Set<T, N> {
+    type Output = Set<T, N>;
+
+    /// Make a new set with the values present in both sets.
+    #[inline]
+    fn bitand(self, rhs: &Set<T, N>) -> Set<T, N> {
+        let mut s = Set::new();
+        for k in self.intersection(rhs) {
+            s.insert(k.clone());
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn set_of<const N: usize>(items: &[i32]) -> Set<i32, N> {
+        let mut s = Set::new();
+        for i in items {
+            s.insert(*i);
+        }
+        s
+    }
+
+    #[test]
+    fn inserts_and_removes() {
+        let mut s: Set<&str, 4> = Set::new();
+        s.insert("one");
+        s.insert("two");
+        s.insert("one");
+        assert_eq!(2, s.len());
+        s.remove("one");
+        assert!(!s.contains("one"));
+        assert!(s.contains("two"));
+    }
+
+    #[test]
+    fn intersects_sets() {
+        let a: Set<i32, 4> = set_of(&[1, 2, 3]);
+        let b: Set<i32, 8> = set_of(&[2, 3, 4]);
+        assert_eq!(a.intersection(&b).collect::<Vec<_>>(), [&2, &3]);
+    }
+
+    #[test]
+    fn subtracts_sets() {
+        let a: Set<i32, 4> = set_of(&[1, 2, 3]);
+        let b: Set<i32, 8> = set_of(&[2, 3, 4]);
+        assert_eq!(a.difference(&b).collect::<Vec<_>>(), [&1]);
+        assert_eq!(b.difference(&a).collect::<Vec<_>>(), [&4]);
+    }
+
+    #[test]
+    fn unites_with_operator() {
+        let a: Set<i32, 8> = set_of(&[1, 2, 3]);
+        let b: Set<i32, 8> = set_of(&[2, 3, 4]);
+        let u = &a | &b;
+        assert_eq!(4, u.len());
+        assert_eq!(u.iter().collect::<Vec<_>>(), [&1, &2, &3, &4]);
+    }
+
+    #[test]
+    fn intersects_with_operator() {
+        let a: Set<i32, 8> = set_of(&[1, 2, 3]);
+        let b: Set<i32, 8> = set_of(&[2, 3, 4]);
+        let i = &a & &b;
+        assert_eq!(i.iter().collect::<Vec<_>>(), [&2, &3]);
+    }
+
+    #[test]
+    fn clones_set_with_hole() {
+        let mut s: Set<i32, 4> = set_of(&[1, 2, 3]);
+        s.remove(&2);
+        let c = s.clone();
+        assert_eq!(2, c.len());
+        assert!(c.contains(&3));
+        assert!(!c.contains(&2));
+        assert_eq!(s, c);
+    }
+
+    #[test]
+    fn debugs_set() {
+        let s: Set<i32, 4> = set_of(&[1, 2, 3]);
+        assert_eq!("{1, 2, 3}", format!("{s:?}"));
+    }
+
+    #[test]
+    fn compares_and_collects_sets() {
+        let a: Set<i32, 4> = [3, 1, 2, 1].into_iter().collect();
+        let b: Set<i32, 8> = set_of(&[1, 2, 3]);
+        assert_eq!(3, a.len());
+        assert!(a == b);
+        assert_ne!(a, Set::<i32, 4>::default());
+    }
+}
@@ -19,15 +19,17 @@
 // SOFTWARE.
 
 use crate::{IntoValues, Map, Values, ValuesMut};
+use core::iter::FusedIterator;
+use core::ops::{AddAssign, MulAssign};
 
 impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
-    /// An iterator visiting all values in arbitrary order.
+    /// An iterator visiting all values in the order of the slots.
     #[inline]
     pub const fn values(&self) -> Values<'_, K, V, N> {
         Values { iter: self.iter() }
     }
 
-    /// An iterator visiting all values mutably in arbitrary order.
+    /// An iterator visiting all values mutably in the order of the slots.
     #[inline]
     pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
         ValuesMut {
@@ -35,7 +37,62 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         }
     }
 
-    /// Consuming iterator visiting all the values in arbitrary order.
+    /// Apply the function to every value, passing its key along.
+    #[inline]
+    pub fn map_values_in_place<F: FnMut(&K, &mut V)>(&mut self, mut f: F) {
+        for (k, v) in self.iter_mut() {
+            f(k, v);
+        }
+    }
+
+    /// Multiply every value by the factor.
+    #[inline]
+    pub fn scale_values(&mut self, factor: V)
+    where
+        V: Copy + MulAssign,
+    {
+        for v in self.values_mut() {
+            *v *= factor;
+        }
+    }
+
+    /// Add the offset to every value.
+    #[inline]
+    pub fn offset_values(&mut self, offset: V)
+    where
+        V: Copy + AddAssign,
+    {
+        for v in self.values_mut() {
+            *v += offset;
+        }
+    }
+
+    /// Copy all values into the front of a new array, returning it together
+    /// with the number of values copied.
+    ///
+    /// The rest of the array is filled with default values.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there are more values than the array can fit, in any
+    /// mode, because the array is never written past its end.
+    #[inline]
+    #[must_use]
+    pub fn collect_values<const M: usize>(&self) -> ([V; M], usize)
+    where
+        V: Copy + Default,
+    {
+        let mut arr = [V::default(); M];
+        let mut n = 0;
+        for v in self.values() {
+            debug_assert!(n < M, "No more space available in the array");
+            arr[n] = *v;
+            n += 1;
+        }
+        (arr, n)
+    }
+
+    /// Consuming iterator visiting all the values in the order of the slots.
     #[inline]
     pub fn into_values(self) -> IntoValues<K, V, N> {
         IntoValues {
@@ -51,8 +108,17 @@ impl<'a, K: PartialEq, V, const N: usize> Iterator for Values<'a, K, V, N> {
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|p| p.1)
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
+impl<K: PartialEq, V, const N: usize> ExactSizeIterator for Values<'_, K, V, N> {}
+
+impl<K: PartialEq, V, const N: usize> FusedIterator for Values<'_, K, V, N> {}
+
 impl<'a, K: PartialEq, V> Iterator for ValuesMut<'a, K, V> {
     type Item = &'a mut V;
 
@@ -60,8 +126,17 @@ impl<'a, K: PartialEq, V> Iterator for ValuesMut<'a, K, V> {
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|p| p.1)
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
+impl<K: PartialEq, V> ExactSizeIterator for ValuesMut<'_, K, V> {}
+
+impl<K: PartialEq, V> FusedIterator for ValuesMut<'_, K, V> {}
+
 impl<K: PartialEq, V, const N: usize> Iterator for IntoValues<K, V, N> {
     type Item = V;
 
@@ -69,8 +144,17 @@ impl<K: PartialEq, V, const N: usize> Iterator for IntoValues<K, V, N> {
     fn next(&mut self) -> Option<V> {
         self.iter.next().map(|p| p.1)
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
+impl<K: PartialEq, V, const N: usize> ExactSizeIterator for IntoValues<K, V, N> {}
+
+impl<K: PartialEq, V, const N: usize> FusedIterator for IntoValues<K, V, N> {}
+
 #[cfg(test)]
 mod test {
 
@@ -93,6 +177,30 @@ mod test {
         assert_eq!(116, m.values().sum());
     }
 
+    #[test]
+    fn maps_values_in_place() {
+        let mut m: Map<i32, i32, 10> = Map::new();
+        m.insert(2, 10);
+        m.insert(3, 100);
+        m.map_values_in_place(|k, v| *v *= k);
+        assert_eq!(20, m[&2]);
+        assert_eq!(300, m[&3]);
+    }
+
+    #[test]
+    fn collects_values_into_array() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("one", 1);
+        m.insert("two", 2);
+        m.insert("three", 3);
+        m.insert("four", 4);
+        m.remove("two");
+        let (arr, n): ([i32; 8], usize) = m.collect_values();
+        assert_eq!(3, n);
+        assert_eq!([1, 3, 4], arr[..n]);
+        assert_eq!([0; 5], arr[n..]);
+    }
+
     #[test]
     fn iterate_values_with_blanks() {
         let mut m: Map<String, i32, 10> = Map::new();
@@ -103,6 +211,38 @@ mod test {
         assert_eq!(m.values().collect::<Vec<_>>(), [&1, &5]);
     }
 
+    #[test]
+    fn collects_into_values() {
+        let mut m: Map<i32, i32, 10> = Map::new();
+        for i in 0..5 {
+            m.insert(i, i * 10);
+        }
+        m.remove(&2);
+        let mut values = m.into_values();
+        assert_eq!(4, values.len());
+        values.next();
+        assert_eq!(3, values.len());
+        let rest = values.collect::<Vec<_>>();
+        assert_eq!(80, rest.iter().sum::<i32>());
+    }
+
+    #[test]
+    fn into_values_drop_early() {
+        use std::rc::Rc;
+        let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        let v = Rc::new(());
+        for i in 0..8 {
+            m.insert(i, Rc::clone(&v));
+        }
+        let mut values = m.into_values();
+        let first = values.next();
+        assert_eq!(9, Rc::strong_count(&v));
+        drop(values);
+        assert_eq!(2, Rc::strong_count(&v));
+        drop(first);
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
     #[test]
     fn into_values_drop() {
         use std::rc::Rc;
@@ -115,4 +255,27 @@ mod test {
         m.into_values();
         assert_eq!(1, Rc::strong_count(&v));
     }
+
+    #[test]
+    fn values_know_their_length() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.remove(&1);
+        assert_eq!(1, m.values().len());
+        assert_eq!(1, m.values_mut().len());
+    }
+
+    #[test]
+    fn scales_and_offsets_values() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.insert(3, 3);
+        m.remove(&2);
+        m.scale_values(2);
+        assert_eq!(8, m.values().sum::<i32>());
+        m.offset_values(-1);
+        assert_eq!(6, m.values().sum::<i32>());
+    }
 }
@@ -0,0 +1,143 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Copy all pairs into a new vector, in the order of the slots.
+    #[inline]
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<(K, V)>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        self.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+    }
+
+    /// Copy all pairs into a new vector, sorted by keys.
+    #[inline]
+    #[must_use]
+    pub fn to_sorted_vec(&self) -> Vec<(K, V)>
+    where
+        K: Clone + Ord,
+        V: Clone,
+    {
+        let mut v = self.to_vec();
+        v.sort_by(|a, b| a.0.cmp(&b.0));
+        v
+    }
+
+    /// Move all pairs into a new boxed slice, in the order of the slots.
+    #[inline]
+    #[must_use]
+    pub fn into_boxed_slice(self) -> Box<[(K, V)]> {
+        self.into_iter().collect()
+    }
+
+    /// Consuming iterator visiting all pairs sorted by keys.
+    #[inline]
+    pub fn into_iter_sorted(self) -> vec::IntoIter<(K, V)>
+    where
+        K: Ord,
+    {
+        let mut v: Vec<(K, V)> = self.into_iter().collect();
+        v.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        v.into_iter()
+    }
+
+    /// Move all pairs into two vectors: the pairs matching the predicate
+    /// go to the first one, all others go to the second one.
+    ///
+    /// The pairs keep the order of the slots in both vectors.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn into_partition<F: FnMut(&K, &V) -> bool>(
+        self,
+        mut pred: F,
+    ) -> (Vec<(K, V)>, Vec<(K, V)>) {
+        self.into_iter().partition(|(k, v)| pred(k, v))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn partitions_into_vectors() {
+        let mut m: Map<i32, i32, 8> = Map::new();
+        for i in 0..6 {
+            m.insert(i, i * 10);
+        }
+        let (big, small) = m.into_partition(|_, v| *v >= 30);
+        assert_eq!(vec![(3, 30), (4, 40), (5, 50)], big);
+        assert_eq!(vec![(0, 0), (1, 10), (2, 20)], small);
+    }
+
+    #[test]
+    fn copies_into_vectors() {
+        let mut m: Map<i32, &str, 8> = Map::new();
+        m.insert(3, "three");
+        m.insert(1, "one");
+        m.insert(2, "two");
+        m.remove(&1);
+        m.insert(4, "four");
+        assert_eq!(m.len(), m.to_vec().len());
+        assert_eq!(vec![(3, "three"), (4, "four"), (2, "two")], m.to_vec());
+        assert_eq!(
+            vec![(2, "two"), (3, "three"), (4, "four")],
+            m.to_sorted_vec()
+        );
+    }
+
+    #[test]
+    fn consumes_sorted_by_keys() {
+        let mut m: Map<i32, String, 8> = Map::new();
+        for i in [5, 2, 7, 1] {
+            m.insert(i, i.to_string());
+        }
+        let pairs: Vec<_> = m.into_iter_sorted().collect();
+        assert_eq!(
+            vec![
+                (1, "1".to_string()),
+                (2, "2".to_string()),
+                (5, "5".to_string()),
+                (7, "7".to_string())
+            ],
+            pairs
+        );
+    }
+
+    #[test]
+    fn moves_into_boxed_slice() {
+        let mut m: Map<i32, &str, 4> = Map::new();
+        m.insert(1, "one");
+        m.insert(2, "two");
+        m.insert(3, "three");
+        let b = m.into_boxed_slice();
+        assert_eq!(3, b.len());
+        assert_eq!([(1, "one"), (2, "two"), (3, "three")], *b);
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+use zeroize::Zeroize;
+
+impl<K: PartialEq + Zeroize, V: Zeroize, const N: usize> Map<K, V, N> {
+    /// Remove all pairs from it, like [`Map::clear`] does, but zeroize
+    /// every key and value before dropping it, in order to leave no
+    /// secrets in the memory of the map.
+    #[inline]
+    pub fn clear_and_zero(&mut self) {
+        for i in 0..self.next {
+            if let Some((k, v)) = unsafe { self.pairs[i].assume_init_mut() } {
+                k.zeroize();
+                v.zeroize();
+            }
+            unsafe { self.pairs[i].assume_init_drop() };
+        }
+        self.next = 0;
+        self.check_trimmed();
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct Secret {
+        bytes: [u8; 4],
+        seen: Rc<RefCell<Vec<u8>>>,
+    }
+
+    impl Zeroize for Secret {
+        fn zeroize(&mut self) {
+            self.bytes.zeroize();
+        }
+    }
+
+    impl Drop for Secret {
+        fn drop(&mut self) {
+            self.seen.borrow_mut().extend_from_slice(&self.bytes);
+        }
+    }
+
+    #[test]
+    fn zeroizes_pairs_on_clear() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let mut m: Map<u8, Secret, 4> = Map::new();
+        m.insert(
+            1,
+            Secret {
+                bytes: [1, 2, 3, 4],
+                seen: Rc::clone(&seen),
+            },
+        );
+        m.insert(
+            2,
+            Secret {
+                bytes: [5, 6, 7, 8],
+                seen: Rc::clone(&seen),
+            },
+        );
+        m.remove(&1);
+        seen.borrow_mut().clear();
+        m.clear_and_zero();
+        assert!(m.is_empty());
+        assert_eq!(vec![0, 0, 0, 0], *seen.borrow());
+    }
+}
@@ -21,6 +21,8 @@
 // In order to run this single test from the command line:
 // $ cargo test --test benchmark -- --nocapture
 
+#![allow(clippy::reversed_empty_ranges)]
+
 use std::collections::HashMap;
 use std::env;
 use std::time::{Duration, Instant};